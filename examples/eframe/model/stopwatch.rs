@@ -1,11 +1,16 @@
+use std::time::Duration;
 use serde::{Deserialize, Serialize};
-use frand_node::*;
+use frand_node::{signal::Signal, *};
+
+const TICK: Duration = Duration::from_millis(50);
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Node)]
 pub struct Stopwatch {
-    pub elapsed: f32,
+    pub elapsed: Duration,
     pub enabled: bool,
-    pub reset: (),
+    pub reset: Signal<()>,
+    run: u32,
+    tick: u32,
 }
 
 impl System for Stopwatch {
@@ -17,32 +22,34 @@ impl System for Stopwatch {
         use stopwatch::Message::*;
 
         match message {
-            // elapsed 가 emit 되고 enabled 가 true 일때
-            // 이전 Tick 으로부터의 delta 를 elapsed 에 더하여
-            // elapsed.emit_carry() 를 호출하여 다음 Tick 에 동작 예약
-            Elapsed(elapsed) if node.enabled.v() => {
-                let delta = delta.unwrap_or_default().as_secs_f32();
-
-                node.elapsed.emit_carry(
-                    move || elapsed + delta
-                );
+            // 현재 run 의 tick 이 emit 되고 enabled 가 true 일때
+            // tick 을 emit_after 한 뒤로 지난 delta 를 elapsed 에 더하고
+            // tick.emit_after() 를 호출하여 TICK 뒤에 동작 예약
+            Tick(run) if run == node.run.v() && node.enabled.v() => {
+                let delta = delta.unwrap_or_default();
+
+                node.elapsed.emit(node.elapsed.v() + delta);
+                node.tick.emit_after(TICK, run);
             },
 
             // enabled 에 true 가 emit 되었을 때
-            // elapsed 를 emit 하여 elapsed 를 재시동
+            // run 을 바꾸어 이전에 예약된 tick 을 무시하고 새 tick 을 예약
             Enabled(enabled) if enabled => {
-                node.elapsed.emit(node.elapsed.v());
+                let run = node.run.v().wrapping_add(1);
+
+                node.run.emit(run);
+                node.tick.emit_after(TICK, run);
             },
 
             // reset 이 emit 되었을 때 
             // enabled 와 elapsed 를 emit 하여 초기화 및 정지
             Reset(_) => {
                 node.enabled.emit(false);
-                node.elapsed.emit_carry(|| 0.0);
+                node.elapsed.emit(Duration::ZERO);
             },
 
             // 그 외의 메시지를 fallback 하여 전달
             message => Self::fallback(node, message, delta),
         }       
     }
-}
\ No newline at end of file
+}
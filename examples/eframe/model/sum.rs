@@ -22,11 +22,12 @@ impl System for Sum {
             // a 또는 b 가 emit 되면 sum1.sum 에 sum1.a + sum1.b 를 emit          
             A(_) | B(_) => {
                 let sum = node.a.v() + node.b.v();
-                node.sum.emit_future(async move {
+                // 대기 중인 이전 합은 취소하고 최신 합만 적용
+                node.sum.emit_future_latest(async move {
                     // 적용 전 1초 비동기 대기
                     sleep(Duration::from_millis(1000)).await;
                     sum 
-                })
+                });
             },
             
             // 그 외의 메시지를 fallback 하여 전달
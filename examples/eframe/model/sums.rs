@@ -33,13 +33,13 @@ impl System for Sums {
             },            
 
             // sums 에 emit 되었을 때
-            // sums 의 모든 값들을 Box에 모아 1초뒤에 그 합을 emit
+            // sums 의 모든 값들을 Box에 모아 1초뒤에 그 합을 emit, 대기 중인 이전 합은 취소
             Sums(_) => {
                 let values: Box<_> = node.sums.items().map(|n| n.v()).collect();
-                node.total.emit_future(async move {
+                node.total.emit_future_latest(async move {
                     sleep(Duration::from_millis(1000)).await;
                     values.iter().sum()
-                })
+                });
             },       
 
             // 그 외의 메시지를 fallback 하여 전달
@@ -2,12 +2,16 @@ use serde::{Deserialize, Serialize};
 use frand_node::ext::*;
 use eframe::egui::*;
 
+const TICK: std::time::Duration = std::time::Duration::from_millis(50);
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Node)]
 pub struct Glow {
     pub intensity: f32,
     pub glow_sec: f32,
     value: u128,
     max: u128,
+    run: u32,
+    tick: u32,
 }
 
 impl System for Glow {
@@ -19,33 +23,39 @@ impl System for Glow {
         use glow::Message::*;
 
         match message {
-            // glow_sec 에 emit 되면 value 와 max 를 emit 하여 glow 시작
+            // glow_sec 에 emit 되면 value 와 max 를 emit 하고
+            // run 을 바꾸어 이전에 예약된 tick 을 무시하고 새 tick 을 예약하여 glow 시작
             GlowSec(sec) => {
                 let max = (sec * 1000.0) as u128;
-                node.value.emit(max); 
+                let run = node.run.v().wrapping_add(1);
+
                 node.max.emit(max); 
+                node.value.emit(max); 
+                node.run.emit(run);
+                node.tick.emit_after(TICK, run);
             },
 
-            Value(value) => {          
+            // 현재 run 의 tick 이 emit 되었을 때
+            // tick 을 emit_after 한 뒤로 지난 delta 를 value 에서 빼고
+            // value 가 남아 있다면 tick.emit_after() 를 호출하여 TICK 뒤에 동작 예약
+            Tick(run) if run == node.run.v() => {
+                let delta = delta.unwrap_or_default().as_millis();
+                let value = node.value.v().saturating_sub(delta);
+
+                node.value.emit(value);
+
                 if 0 < value {
-                    // emit 된 value 가 0 보다 클 때      
-                    // (value / max) 를 intensity 에 emit
-                    // (value - delta) 를 value 에 emit_carry 하여 다음 Tick 에 동작 예약
-                    
-                    let delta = delta.unwrap_or_default().as_millis();
-
-                    node.intensity.emit(
-                        (node.value.v() as f32) / (node.max.v() as f32)
-                    );
-
-                    node.value.emit_carry(
-                        move || value.saturating_sub(delta)
-                    ); 
-                } else {
-                    node.intensity.emit(0.0);
+                    node.tick.emit_after(TICK, run);
                 }
             },
 
+            // value 가 emit 되면 (value / max) 를 intensity 에 emit
+            Value(value) => {
+                node.intensity.emit(
+                    (value as f32) / (node.max.v().max(1) as f32)
+                );
+            },
+
             // 그 외의 메시지를 fallback 하여 전달
             message => Self::fallback(node, message, delta),
         }        
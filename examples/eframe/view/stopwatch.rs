@@ -14,7 +14,7 @@ impl Widget for stopwatch_view::Node<'_> {
     fn ui(self, ui: &mut Ui) -> Response {      
         ui.horizontal(|ui| {
             if let Some(model) = self.model.subject() { 
-                ui.label(format!("elapsed : {:.1}", model.elapsed.v()));
+                ui.label(format!("elapsed : {:.1}", model.elapsed.v().as_secs_f32()));
     
                 let start_stop_text = if model.enabled.v() { 
                     "stop" 
@@ -27,7 +27,7 @@ impl Widget for stopwatch_view::Node<'_> {
                 }
         
                 if ui.button("reset").clicked() {
-                    model.reset.emit(());
+                    model.reset.emit_signal(());
                 }   
             } else {
                 ui.label("The model is not applied.");
@@ -44,13 +44,13 @@ impl Widget for sums_view::Node<'_> {
                 ui.horizontal(|ui| {
                     let len = model.values.len();
         
-                    if ui.button(format!(" - ")).clicked() {
+                    if ui.button(" - ").clicked() {
                         self.values.emit_pop();
                     }
         
                     ui.label(format!(" len: {len} "));
         
-                    if ui.button(format!(" + ")).clicked() {
+                    if ui.button(" + ").clicked() {
                         self.values.emit_push(Default::default());
                     }
                 });
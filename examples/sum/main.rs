@@ -42,14 +42,14 @@ fn run(iter: u32) {
 
     for i in 0..iter {
         // a 와 b 에 새로운 값 emit
-        sum.node().a.emit(i * 1);
+        sum.node().a.emit(i);
         sum.node().b.emit(i * 2);   
          
         // try_update 로 적용
         sum.try_update();
 
         // 값 확인
-        assert_eq!(sum.node().a.v(), i * 1, "sum.a");
+        assert_eq!(sum.node().a.v(), i, "sum.a");
         assert_eq!(sum.node().b.v(), i * 2, "sum.b");
         assert_eq!(sum.node().sum.v(), i * 3, "sum.sum");
     }
@@ -10,10 +10,21 @@ use quote::quote;
 use syn::*;
 
 mod node;
+mod terminal;
 
 #[proc_macro_derive(Node)]
 pub fn node(item: TokenStream) -> TokenStream {   
-    let state = parse_macro_input!(item as ItemStruct);
+    let state = match parse_macro_input!(item as Item) {
+        Item::Struct(state) => state,
+        Item::Enum(state) => return Error::new_spanned(
+            state.enum_token, 
+            "Node cannot be derived for enums, derive Terminal instead",
+        ).into_compile_error().into(),
+        item => return Error::new_spanned(
+            item, 
+            "Node can only be derived for structs",
+        ).into_compile_error().into(),
+    };
 
     let node = node::expand(state, quote!{ frand_node::ext })
     .unwrap_or_else(Error::into_compile_error);
@@ -23,12 +34,24 @@ pub fn node(item: TokenStream) -> TokenStream {
     }.into()
 }
 
+#[proc_macro_derive(Terminal)]
+pub fn terminal(item: TokenStream) -> TokenStream {
+    let state = parse_macro_input!(item as DeriveInput);
+
+    let terminal = terminal::expand(state, quote!{ frand_node::ext })
+    .unwrap_or_else(Error::into_compile_error);
+
+    quote! {
+        #terminal
+    }.into()
+}
+
 #[proc_macro_derive(NodeMacro)]
 pub fn node_macro(item: TokenStream) -> TokenStream {
     let state = parse_macro_input!(item as ItemStruct);
 
     let macro_name = Ident::new(
-        &format!("{}_node", state.ident.to_string()).to_case(Case::Snake), 
+        &format!("{}_node", state.ident).to_case(Case::Snake), 
         state.ident.span(),
     );
 
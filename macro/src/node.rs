@@ -32,16 +32,16 @@ pub fn expand(
     };
 
     let impl_generics = {
-        let lt_token = state.generics.lt_token.clone();
-        let gt_token = state.generics.gt_token.clone();
+        let lt_token = state.generics.lt_token;
+        let gt_token = state.generics.gt_token;
         quote! {
             #lt_token #impl_params #gt_token
         }
     };
 
     let ty_generics = {
-        let lt_token = state.generics.lt_token.clone();
-        let gt_token = state.generics.gt_token.clone();
+        let lt_token = state.generics.lt_token;
+        let gt_token = state.generics.gt_token;
         quote! {
             #lt_token #ty_params #gt_token
         }
@@ -65,7 +65,7 @@ pub fn expand(
     let id_delta_names: Vec<_> = fields.iter().filter_map(|field| 
         field.ident.as_ref().map(|name| {
             Ident::new(
-                &format!("{}IdDelta", name.to_string()).to_case(Case::UpperSnake), 
+                &format!("{}IdDelta", name).to_case(Case::UpperSnake), 
                 name.span(),
             )
         })        
@@ -74,7 +74,7 @@ pub fn expand(
     let id_delta_end_names: Vec<_> = fields.iter().filter_map(|field| 
         field.ident.as_ref().map(|name| {
             Ident::new(
-                &format!("{}IdDeltaEnd", name.to_string()).to_case(Case::UpperSnake), 
+                &format!("{}IdDeltaEnd", name).to_case(Case::UpperSnake), 
                 name.span(),
             )
         })        
@@ -84,12 +84,11 @@ pub fn expand(
         quote!{ <#ty as #ext::State>::NODE_SIZE }
     ).collect();
 
-    let id_deltas: Vec<_> = (0..fields.len()).into_iter()
+    let id_deltas: Vec<_> = (0..fields.len())
     .map(|index| {
         let mut tokens = quote!{ 1 };
 
-        for i in 0..index {
-            let node_size = &node_sizes[i];
+        for node_size in node_sizes.iter().take(index) {
             quote!{
                 + #node_size
             }.to_tokens(&mut tokens);
@@ -191,6 +190,7 @@ pub fn expand(
             impl #impl_generics #ext::Message for Message #ty_generics {    
                 type State = #state_name #ty_generics;
 
+                #[allow(clippy::needless_question_mark)]
                 fn from_packet(
                     packet: &#ext::Packet,
                     parent_key: #ext::Key,
@@ -198,7 +198,7 @@ pub fn expand(
                 ) -> #ext::Result<Self> {
                     Ok(match packet.key().consist().id() - parent_key.consist().id() {
                         0 => Ok(Self::State(
-                            #ext::State::from_payload(packet.payload())
+                            packet.decode(depth)?
                         )),
                         #(#id_delta_names..#id_delta_end_names => Ok(
                             Message::#pascal_names(#message_tys::from_packet(
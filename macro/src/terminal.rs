@@ -0,0 +1,78 @@
+use proc_macro2::TokenStream;
+use syn::*;
+use quote::quote;
+
+pub fn expand(
+    state: DeriveInput,
+    ext: TokenStream,
+) -> Result<TokenStream> {
+    let state_name = state.ident.clone();
+
+    let mut generics = state.generics.clone();
+    let ty_params: Vec<_> = generics.type_params().map(|param| param.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+
+    for ty_param in ty_params {
+        where_clause.predicates.push(parse_quote!{ #ty_param: #ext::State });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote!{
+        impl #impl_generics #ext::State for #state_name #ty_generics #where_clause {
+            const NODE_SIZE: #ext::IdSize = 1;
+            const NODE_ALT_SIZE: #ext::AltSize = 0;
+
+            type Message = Self;
+            type Emitter = #ext::terminal::Emitter<Self>;
+            type Accesser = #ext::terminal::Accesser<Self>;
+            type Node<'n> = #ext::terminal::Node<'n, Self>;
+
+            fn from_payload(payload: &#ext::Payload) -> Self {
+                #ext::Payload::to_state(payload)
+            }
+
+            fn to_payload(&self) -> #ext::Payload {
+                #ext::Payload::from_state(self)
+            }
+
+            fn into_message(self) -> Self::Message {
+                self
+            }
+        }
+
+        impl #impl_generics #ext::Message for #state_name #ty_generics #where_clause {
+            type State = Self;
+
+            fn from_packet(
+                packet: &#ext::Packet,
+                _parent_key: #ext::Key,
+                depth: usize,
+            ) -> #ext::Result<Self> {
+                packet.decode(depth)
+            }
+
+            fn to_packet(
+                &self,
+                key: #ext::Key,
+            ) -> #ext::Packet {
+                #ext::Packet::new(key, #ext::State::to_payload(self))
+            }
+
+            fn apply_to(&self, state: &mut Self::State) {
+                *state = self.clone();
+            }
+        }
+
+        impl #impl_generics #ext::Fallback for #state_name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn fallback(
+                node: Self::Node<'_>,
+                message: Self::Message,
+                delta: Option<std::time::Duration>,
+            ) {}
+        }
+
+        impl #impl_generics #ext::System for #state_name #ty_generics #where_clause { }
+    })
+}
@@ -1,5 +1,8 @@
-use std::{any::type_name_of_val, future::Future, sync::Arc};
+use std::{any::type_name_of_val, future::Future, sync::Arc, time::Duration};
+use futures::stream::{self, Stream};
+use tokio::time::{interval_at, sleep_until, Instant, MissedTickBehavior};
 use crate::ext::*;
+use super::packet::MessagePacketFuture;
 
 #[derive(Debug, Clone, Copy)]
 pub enum CallbackMode {
@@ -10,8 +13,8 @@ pub enum CallbackMode {
 #[derive( Clone)]
 pub struct Callback<S: State>{
     consist: Consist,
-    callback: Arc<dyn Fn(MessagePacket<S>) + Send + Sync>,
-    process: Arc<dyn Fn(MessagePacket<S>) + Send + Sync>,
+    callback: Arc<dyn Fn(MessagePacket<S>) -> Result<(), EmitError> + Send + Sync>,
+    process: Arc<dyn Fn(MessagePacket<S>) -> Result<(), EmitError> + Send + Sync>,
 }
 
 impl<S: State + std::fmt::Debug> std::fmt::Debug for Callback<S> {
@@ -26,13 +29,13 @@ impl<S: State + std::fmt::Debug> std::fmt::Debug for Callback<S> {
 
 impl<S: State> Callback<S> {
     pub fn consist(&self) -> &Consist { &self.consist }
-    pub fn callback(&self) -> &Arc<dyn Fn(MessagePacket<S>) + Send + Sync> { &self.callback }
-    pub fn process(&self) -> &Arc<dyn Fn(MessagePacket<S>) + Send + Sync> { &self.process }
+    pub fn callback(&self) -> &Arc<dyn Fn(MessagePacket<S>) -> Result<(), EmitError> + Send + Sync> { &self.callback }
+    pub fn process(&self) -> &Arc<dyn Fn(MessagePacket<S>) -> Result<(), EmitError> + Send + Sync> { &self.process }
 
     pub fn new(
         consist: Consist,
-        callback: Arc<dyn Fn(MessagePacket<S>) + Send + Sync>,
-        process: Arc<dyn Fn(MessagePacket<S>) + Send + Sync>,
+        callback: Arc<dyn Fn(MessagePacket<S>) -> Result<(), EmitError> + Send + Sync>,
+        process: Arc<dyn Fn(MessagePacket<S>) -> Result<(), EmitError> + Send + Sync>,
     ) -> Self {
         Self { 
             consist, 
@@ -43,8 +46,8 @@ impl<S: State> Callback<S> {
 
     pub fn access<P: State>(
         consist: Consist,
-        callback: Arc<dyn Fn(MessagePacket<P>) + Send + Sync>,
-        process: Arc<dyn Fn(MessagePacket<P>) + Send + Sync>,
+        callback: Arc<dyn Fn(MessagePacket<P>) -> Result<(), EmitError> + Send + Sync>,
+        process: Arc<dyn Fn(MessagePacket<P>) -> Result<(), EmitError> + Send + Sync>,
         id_delta: IdDelta,
         wrap: fn(AltIndex, S::Message) -> P::Message,
     ) -> Self {   
@@ -67,48 +70,258 @@ impl<S: State> Callback<S> {
         transient: &Transient, 
         message: S::Message,
     ) {
+        EmitError::report(self.try_emit(mode, transient, message));
+    }
+
+    pub fn emit_carry<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        lookup: F,
+    ) where F: Fn() -> S::Message + 'static + Send + Sync {
+        EmitError::report(self.try_emit_carry(mode, transient, lookup));
+    }
+
+    pub fn emit_carry_timed<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        timing: CarryTiming,
+        lookup: F,
+    ) where F: Fn() -> S::Message + 'static + Send + Sync {
+        EmitError::report(self.try_emit_carry_timed(mode, transient, timing, lookup));
+    }
+
+    pub fn emit_future<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        future: F,
+    ) -> FutureHandle
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        let (handle, result) = self.send_future(mode, transient, future, false);
+        EmitError::report(result);
+        handle
+    }
+
+    pub fn emit_future_latest<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        future: F,
+    ) -> FutureHandle
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        let (handle, result) = self.send_future(mode, transient, future, true);
+        EmitError::report(result);
+        handle
+    }
+
+    // 대기는 emit 한 시점부터 tokio 시간으로 재며 update 가 메시지를 받아 처리함
+    pub fn emit_after(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        duration: Duration,
+        message: S::Message,
+    ) -> FutureHandle {
+        let (handle, result) = self.send_after(mode, transient, duration, message);
+        EmitError::report(result);
+        handle
+    }
+
+    // emit 한 시점부터 period 마다 lookup 의 결과를 emit 하며 취소될 때까지 계속됨
+    // update 가 늦어 놓친 주기는 몰아서 emit 하지 않고 다음 주기를 늦춤
+    // period 가 0 이면 이미 취소된 핸들을 반환함
+    pub fn emit_interval<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        period: Duration,
+        lookup: F,
+    ) -> FutureHandle 
+    where F: FnMut() -> S::Message + 'static + Send + Sync {
+        let (handle, result) = self.send_interval(mode, transient, period, lookup);
+        EmitError::report(result);
+        handle
+    }
+
+    pub fn try_emit(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        message: S::Message,
+    ) -> Result<(), EmitError> {
         let message = MessagePacket::message(
             Key::new(self.consist, *transient), 
             message,
         );
 
-        match mode {
-            CallbackMode::Default => (self.callback)(message),
-            CallbackMode::Process => (self.process)(message),
-        }
+        self.send(mode, message)
     }
 
-    pub fn emit_carry<F>(
+    pub fn try_emit_carry<F>(
         &self, 
         mode: &CallbackMode,
         transient: &Transient, 
         lookup: F,
-    ) where F: Fn() -> S::Message + 'static + Send + Sync {
-        let message = MessagePacket::carry(
+    ) -> Result<(), EmitError> 
+    where F: Fn() -> S::Message + 'static + Send + Sync {
+        self.try_emit_carry_timed(mode, transient, CarryTiming::Next, lookup)
+    }
+
+    pub fn try_emit_carry_timed<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        timing: CarryTiming,
+        lookup: F,
+    ) -> Result<(), EmitError> 
+    where F: Fn() -> S::Message + 'static + Send + Sync {
+        let message = MessagePacket::carry_timed(
             Key::new(self.consist, *transient), 
+            timing,
             lookup,
         );
 
-        match mode {
-            CallbackMode::Default => (self.callback)(message),
-            CallbackMode::Process => (self.process)(message),
+        self.send(mode, message)
+    }
+
+    pub fn try_emit_future<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        future: F,
+    ) -> Result<FutureHandle, EmitError> 
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        let (handle, result) = self.send_future(mode, transient, future, false);
+        result.map(|_| handle)
+    }
+
+    pub fn try_emit_future_latest<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        future: F,
+    ) -> Result<FutureHandle, EmitError> 
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        let (handle, result) = self.send_future(mode, transient, future, true);
+        result.map(|_| handle)
+    }
+
+    pub fn try_emit_after(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        duration: Duration,
+        message: S::Message,
+    ) -> Result<FutureHandle, EmitError> {
+        let (handle, result) = self.send_after(mode, transient, duration, message);
+        result.map(|_| handle)
+    }
+
+    pub fn try_emit_interval<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        period: Duration,
+        lookup: F,
+    ) -> Result<FutureHandle, EmitError> 
+    where F: FnMut() -> S::Message + 'static + Send + Sync {
+        let (handle, result) = self.send_interval(mode, transient, period, lookup);
+        result.map(|_| handle)
+    }
+
+    fn send_after(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        duration: Duration,
+        message: S::Message,
+    ) -> (FutureHandle, Result<(), EmitError>) {
+        let deadline = Instant::now() + duration;
+
+        self.send_stream(
+            mode, 
+            transient, 
+            stream::once(async move {
+                sleep_until(deadline).await;
+                message
+            }), 
+            false,
+            false,
+        )
+    }
+
+    fn send_interval<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        period: Duration,
+        lookup: F,
+    ) -> (FutureHandle, Result<(), EmitError>)
+    where F: FnMut() -> S::Message + 'static + Send + Sync {
+        // 0 주기로는 끝없이 emit 하게 되므로 아무것도 emit 하지 않음
+        if period.is_zero() {
+            return (FutureHandle::cancelled(), Ok(()));
         }
+
+        let start = Instant::now() + period;
+
+        // Interval 은 tokio 런타임 안에서만 만들 수 있으므로 처음 poll 될 때 만듦
+        let ticks = stream::unfold((None, lookup), move |(interval, mut lookup)| async move {
+            let mut interval = interval.unwrap_or_else(|| {
+                let mut interval = interval_at(start, period);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                interval
+            });
+
+            interval.tick().await;
+            Some((lookup(), (Some(interval), lookup)))
+        });
+
+        self.send_stream(mode, transient, ticks, false, true)
     }
 
-    pub fn emit_future<F>(
+    fn send_future<F>(
         &self, 
         mode: &CallbackMode,
         transient: &Transient, 
         future: F,
-    ) where F: Future<Output = S::Message> + 'static + Send + Sync {
-        let message = MessagePacket::future(
+        latest: bool,
+    ) -> (FutureHandle, Result<(), EmitError>)
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        self.send_stream(mode, transient, stream::once(future), latest, false)
+    }
+
+    fn send_stream<F>(
+        &self, 
+        mode: &CallbackMode,
+        transient: &Transient, 
+        stream: F,
+        latest: bool,
+        repeating: bool,
+    ) -> (FutureHandle, Result<(), EmitError>)
+    where F: Stream<Item = S::Message> + 'static + Send + Sync {
+        let mut future = MessagePacketFuture::from_stream(
             Key::new(self.consist, *transient), 
-            future,
+            stream,
+            latest,
         );
+        future.repeating = repeating;
+
+        let handle = future.handle().clone();
+
+        (handle, self.send(mode, MessagePacket::Future(future)))
+    }
 
+    fn send(
+        &self, 
+        mode: &CallbackMode,
+        message: MessagePacket<S>,
+    ) -> Result<(), EmitError> {
         match mode {
             CallbackMode::Default => (self.callback)(message),
             CallbackMode::Process => (self.process)(message),
         }
     }
-}
\ No newline at end of file
+}
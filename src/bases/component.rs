@@ -1,23 +1,207 @@
-use std::{collections::{HashMap, HashSet}, hash::BuildHasherDefault, ops::Deref, task::{Context, Poll}};
-use futures::{stream::{FuturesUnordered, StreamExt}, task::noop_waker_ref, FutureExt};
+use std::{collections::{HashMap, HashSet, VecDeque}, hash::BuildHasherDefault, io, path::Path, ops::{Deref, DerefMut}, rc::Rc, future::Future, pin::Pin, sync::{Arc, Condvar, Mutex, Weak}, task::{Context, Poll}, time::Duration};
+use futures::{stream::{SelectAll, StreamExt}, task::noop_waker_ref, FutureExt};
 use rustc_hash::FxHasher;
 use smallvec::SmallVec;
-use tokio::{select, sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender}};
-use crate::ext::*;
-use super::packet::{MessagePacketCarry, MessagePacketFuture, MessagePacketMessage};
+use tokio::{select, sync::{mpsc::{unbounded_channel, UnboundedReceiver}, Notify}, time::{sleep_until, Instant}};
+use crate::{ext::*, replica::Clock};
+use super::{consensus::InputSpace, packet::{BatchId, MessagePacketCarry, MessagePacketFuture, MessagePacketMessage}, session::{Recording, SessionInput, SessionTick}};
 
 type Input<M> = SmallVec<[MessagePacket<M>; 4]>;
 type Output<M> = SmallVec<[MessagePacketMessage<M>; 8]>;
 
+// 입력 큐가 가득 찼을 때 새 입력을 처리하는 방식
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    // 공간이 생길 때까지 emit 한 스레드를 멈춤
+    // Component 를 update 하는 스레드에서 emit 하면 교착되므로 주의
+    // tokio runtime 안에서는 worker 를 멈추지 않도록 EmitError::WouldBlock 을 반환하므로
+    // async 문맥에서는 Consensus::emit_async 로 공간이 생길 때까지 기다림
+    #[default]
+    Block,
+    DropOldest,
+    DropNewest,
+    Error,
+}
+
+struct InputQueue<S: State> {
+    packets: Mutex<InputPackets<S>>,
+    capacity: Option<usize>,
+    overflow: Overflow,
+    space: Condvar,
+    space_async: Notify,
+    notify: Notify,
+}
+
+struct InputPackets<S: State> {
+    packets: VecDeque<MessagePacket<S>>,
+    closed: bool,
+}
+
+impl<S: State> std::fmt::Debug for InputQueue<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputQueue")
+        .field("len", &self.packets.lock().unwrap().packets.len())
+        .field("capacity", &self.capacity)
+        .field("overflow", &self.overflow)
+        .finish()
+    }
+}
+
+impl<S: State> InputQueue<S> {
+    fn new(capacity: Option<usize>, overflow: Overflow) -> Self {
+        Self {
+            packets: Mutex::new(InputPackets { 
+                packets: VecDeque::new(), 
+                closed: false,
+            }),
+            capacity: capacity.map(|capacity| capacity.max(1)),
+            overflow,
+            space: Condvar::new(),
+            space_async: Notify::new(),
+            notify: Notify::new(),
+        }
+    }
+
+    fn push(&self, packet: MessagePacket<S>) -> Result<(), EmitError> {
+        let mut packets = self.packets.lock().unwrap();
+
+        if let Some(capacity) = self.capacity {
+            while !packets.closed && capacity <= packets.packets.len() {
+                match self.overflow {
+                    Overflow::Block => if tokio::runtime::Handle::try_current().is_ok() {
+                        return Err(EmitError::WouldBlock);
+                    } else {
+                        packets = self.space.wait(packets).unwrap();
+                    },
+                    // carry 는 버리지 않고 가장 오래된 입력을 버림
+                    Overflow::DropOldest => match packets.packets.iter().position(
+                        |packet| !matches!(packet, MessagePacket::Carry(_))
+                    ) {
+                        Some(index) => { packets.packets.remove(index); },
+                        None => break,
+                    },
+                    Overflow::DropNewest => return Ok(()),
+                    Overflow::Error => return Err(EmitError::Full),
+                }
+            }
+        }
+
+        self.push_unbounded(packets, packet)
+    }
+
+    fn push_unbounded(
+        &self, 
+        mut packets: std::sync::MutexGuard<'_, InputPackets<S>>, 
+        packet: MessagePacket<S>,
+    ) -> Result<(), EmitError> {
+        if packets.closed {
+            return Err(EmitError::Closed);
+        }
+
+        packets.packets.push_back(packet);
+        drop(packets);
+
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    fn drain(&self, input: &mut Input<S>) {
+        input.extend(self.packets.lock().unwrap().packets.drain(..));
+        self.space.notify_all();
+        self.space_async.notify_waiters();
+    }
+
+    fn close(&self) {
+        self.packets.lock().unwrap().closed = true;
+        self.space.notify_all();
+        self.space_async.notify_waiters();
+    }
+}
+
+impl<S: State> InputSpace for InputQueue<S> {
+    fn ready(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            loop {
+                // 확인과 대기 사이에 비워져도 놓치지 않도록 먼저 등록함
+                let notified = self.space_async.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                {
+                    let packets = self.packets.lock().unwrap();
+
+                    if packets.closed || self.capacity.is_none_or(|capacity| packets.packets.len() < capacity) {
+                        return;
+                    }
+                }
+
+                notified.await;
+            }
+        })
+    }
+}
+
+pub const DEFAULT_CASCADE_LIMIT: usize = 1024;
+// take_errors 로 꺼내지 않으면 가장 최근의 연쇄 오류만 이만큼 보관함
+pub const MAX_CASCADE_ERRORS: usize = 256;
+
+// 하나의 입력에서 process 모드의 emit 으로 이어진 Key 의 연쇄
+struct Chain {
+    key: Key,
+    depth: usize,
+    parent: Option<Rc<Chain>>,
+}
+
+impl Chain {
+    fn contains(chain: &Option<Rc<Chain>>, key: &Key) -> bool {
+        let mut chain = chain;
+
+        while let Some(link) = chain {
+            if link.key == *key {
+                return true;
+            }
+            chain = &link.parent;
+        }
+
+        false
+    }
+
+    fn keys(chain: &Option<Rc<Chain>>, key: Key) -> Vec<Key> {
+        let mut keys = vec![key];
+        let mut chain = chain;
+
+        while let Some(link) = chain {
+            keys.push(link.key);
+            chain = &link.parent;
+        }
+
+        keys.reverse();
+        keys
+    }
+}
+
 #[derive(Debug)]
 pub struct Component<S: System> {
     consensus: Consensus<S>,
-    input_tx: UnboundedSender<MessagePacket<S>>,
-    input_rx: UnboundedReceiver<MessagePacket<S>>,
+    input: Arc<InputQueue<S>>,
     process_rx: UnboundedReceiver<MessagePacket<S>>,
-    carry: HashMap<Key, MessagePacketCarry<S>, BuildHasherDefault<FxHasher>>,   
-    future: FuturesUnordered<MessagePacketFuture<S>>,
+    // 적용할 시점과 carry, 시점이 None 이면 다음 update 에 적용
+    carry: HashMap<Key, (Option<Instant>, MessagePacketCarry<S>), BuildHasherDefault<FxHasher>>,   
+    // Key 별 throttle 주기가 끝나는 시점
+    throttle: HashMap<Key, Instant, BuildHasherDefault<FxHasher>>,   
+    future: SelectAll<MessagePacketFuture<S>>,
+    latest: HashMap<Key, FutureHandle, BuildHasherDefault<FxHasher>>,
+    repeating: Vec<FutureHandle>,
     updated: HashSet<Key, BuildHasherDefault<FxHasher>>,   
+    cascade_limit: Option<usize>,
+    errors: VecDeque<CascadeError>,
+    batch: BatchId,
+    history: Option<History<S>>,
+    restoring: bool,
+    journal: Option<Journal>,
+    journal_errors: Vec<io::Error>,
+    recording: Option<Recording>,
+    replaying: Option<VecDeque<Option<Duration>>>,
 }
 
 impl<S: System + Default> Default for Component<S> {
@@ -29,38 +213,275 @@ impl<S: System> Deref for Component<S> {
     fn deref(&self) -> &Self::Target { &self.consensus }
 }
 
+impl<S: System> Drop for Component<S> {
+    fn drop(&mut self) { self.input.close() }
+}
+
 impl<S: System> Component<S> {
     pub fn consensus(&self) -> &Consensus<S> { &self.consensus }
 
     pub fn new(state: S) -> Self {
-        let (input_tx, input_rx) = unbounded_channel();
+        Self::with_queue(state, None, Overflow::default())
+    }
+
+    // 입력 큐에 최대 capacity 개의 입력만 보관하고 넘치는 입력은 overflow 에 따라 처리
+    pub fn bounded(state: S, capacity: usize, overflow: Overflow) -> Self {
+        Self::with_queue(state, Some(capacity), overflow)
+    }
+
+    fn with_queue(state: S, capacity: Option<usize>, overflow: Overflow) -> Self {
+        let input = Arc::new(InputQueue::new(capacity, overflow));
         let (process_tx, process_rx) = unbounded_channel();
 
-        let input_tx_clone = input_tx.clone();
-        let process_tx_clone = process_tx.clone();
-        let consensus: Consensus<S> = Consensus::new(
+        let input_weak = Arc::downgrade(&input);
+        let mut consensus: Consensus<S> = Consensus::new(
             state,
-            move |message| input_tx_clone.send(message).unwrap(),
-            move |message| process_tx_clone.send(message).unwrap(),
+            move |message| input_weak.upgrade().ok_or(EmitError::Closed)?.push(message),
+            move |message| process_tx.send(message).map_err(|_| EmitError::Closed),
         );
+        consensus.set_space(Arc::downgrade(&input) as Weak<dyn InputSpace>);
 
         Self { 
             consensus, 
-            input_tx, input_rx,
+            input,
             process_rx,
             carry: HashMap::default(),
-            future: FuturesUnordered::new(),
+            throttle: HashMap::default(),
+            future: SelectAll::new(),
+            latest: HashMap::default(),
+            repeating: Vec::new(),
             updated: HashSet::default(),
+            cascade_limit: Some(DEFAULT_CASCADE_LIMIT),
+            errors: VecDeque::new(),
+            batch: 0,
+            history: None,
+            restoring: false,
+            journal: None,
+            journal_errors: Vec::new(),
+            recording: None,
+            replaying: None,
         }        
     }
 
+    // tokio 런타임 안에서 update 를 반복하는 task 를 실행하고 그 핸들을 반환
+    pub fn spawn(state: S) -> ComponentHandle<S> {
+        Self::new(state).into_handle()
+    }
+
+    pub fn into_handle(self) -> ComponentHandle<S> {
+        ComponentHandle::new(self)
+    }
+
+    // 처리되지 않은 입력, 완료되지 않은 future 나 시점을 기다리는 debounce 와 throttle 의 carry 가 있음
+    // 매 update 마다 다시 emit 될 수 있는 carry 는 제외함
+    pub(crate) fn is_pending(&self) -> bool {
+        !self.future.is_empty() 
+        || self.carry.values().any(|(due, _)| due.is_some())
+        || self.input.packets.lock().unwrap().packets.iter()
+            .any(|packet| !matches!(packet, MessagePacket::Carry(carry) if matches!(carry.timing, CarryTiming::Next)))
+    }
+
+    // 시점을 기다리는 carry 를 다음 update 에 바로 적용하도록 함
+    pub(crate) fn flush_timed(&mut self) {
+        for (due, _) in self.carry.values_mut() {
+            *due = None;
+        }
+    }
+
+    // 완료되지 않은 emit_future_latest 의 Key 수
+    pub fn latest_len(&self) -> usize { self.latest.len() }
+
+    pub(crate) fn cancel_repeating(&mut self) {
+        for handle in self.repeating.drain(..) {
+            handle.cancel();
+        }
+    }
+
+    // 이 Component 의 node 가 emit 하는 CRDT 메시지에 사용할 replica id
+    pub fn with_replica(self, replica: ReplicaId) -> Self {
+        self.consensus.replica_mut().set_id(replica);
+        self
+    }
+
+    // Hlc 에 사용할 시계, update 중에는 update 를 시작한 시각으로 고정됨
+    pub fn with_clock(self, clock: impl Fn() -> u64 + 'static + Send + Sync) -> Self {
+        self.set_clock(Arc::new(clock));
+        self
+    }
+
+    pub(crate) fn set_clock(&self, clock: Clock) {
+        self.consensus.replica_mut().set_clock(clock);
+    }
+
+    pub fn journal(&self) -> Option<&Journal> { self.journal.as_ref() }
+
+    // path 의 snapshot 과 journal 로 상태를 복구하고 이후 적용되는 메시지를 journal 에 기록
+    pub fn recover(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::recover_with(path, JournalPolicy::default())
+    }
+
+    pub fn recover_with(path: impl AsRef<Path>, policy: JournalPolicy) -> io::Result<Self> {
+        let (state, journal) = Journal::recover(path, policy)?;
+        let mut component = Self::new(state);
+        component.journal = Some(journal);
+        Ok(component)
+    }
+
+    // journal 기록 중 발생한 오류를 꺼냄
+    pub fn take_journal_errors(&mut self) -> Vec<io::Error> {
+        std::mem::take(&mut self.journal_errors)
+    }
+
+    pub(crate) fn start_recording(&mut self) {
+        self.recording = Some(Recording::new());
+    }
+
+    pub(crate) fn stop_recording(&mut self) {
+        self.recording = None;
+    }
+
+    pub(crate) fn recorded(&self) -> &[SessionTick] {
+        self.recording.as_ref().map(Recording::ticks).unwrap_or_default()
+    }
+
+    // 기록된 입력을 기록된 delta 로 처리하고 handler 가 남긴 carry 와 future 는 버림
+    pub(crate) fn replay(&mut self, inputs: &[SessionInput]) -> Result<Output<S>> {
+        let mut input: Input<S> = SmallVec::new();
+        let mut deltas = VecDeque::new();
+
+        for recorded in inputs {
+            match recorded {
+                SessionInput::Message { packet, delta } => {
+                    input.push(MessagePacket::message(
+                        packet.key(), 
+                        S::Message::from_packet(packet, Key::default(), 0)?,
+                    ));
+                    deltas.push_back(*delta);
+                },
+                SessionInput::Batch(packets) => {
+                    input.push(MessagePacket::Batch(
+                        packets.iter()
+                        .map(|packet| Ok(MessagePacketMessage {
+                            key: packet.key(),
+                            instant: None,
+                            batch: None,
+                            message: S::Message::from_packet(packet, Key::default(), 0)?,
+                        }))
+                        .collect::<Result<_>>()?
+                    ));
+                },
+            }
+        }
+
+        self.replaying = Some(deltas);
+        let output = self.process(input);
+        self.replaying = None;
+
+        self.input.packets.lock().unwrap().packets.clear();
+        self.carry.clear();
+        self.throttle.clear();
+        self.future.clear();
+        self.latest.clear();
+        self.repeating.clear();
+
+        Ok(output)
+    }
+
+    pub fn history(&self) -> Option<&History<S>> { self.history.as_ref() }
+    pub fn history_mut(&mut self) -> Option<&mut History<S>> { self.history.as_mut() }
+
+    pub fn with_history(mut self, history: History<S>) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    // 마지막 묶음 이전의 상태로 되돌리고 그 결과를 바로 처리하여 반환
+    pub fn undo(&mut self) -> Option<Output<S>> {
+        let current = self.node().clone_state()?;
+        let state = self.history.as_mut()?.undo(current)?;
+        Some(self.restore(state))
+    }
+
+    pub fn redo(&mut self) -> Option<Output<S>> {
+        let current = self.node().clone_state()?;
+        let state = self.history.as_mut()?.redo(current)?;
+        Some(self.restore(state))
+    }
+
+    fn restore(&mut self, state: S) -> Output<S> {
+        let packets = match &self.history {
+            Some(history) => history.restore(state),
+            None => return SmallVec::new(),
+        };
+
+        let mut input: Input<S> = SmallVec::new();
+        input.push(MessagePacket::Batch(packets));
+
+        self.restoring = true;
+        let output = self.process(input);
+        self.restoring = false;
+
+        output
+    }
+
+    // None 이면 연쇄의 깊이를 제한하지 않음
+    pub fn with_cascade_limit(mut self, limit: Option<usize>) -> Self {
+        self.cascade_limit = limit;
+        self
+    }
+
+    // update 중 발생한 연쇄 오류를 꺼냄
+    // 최대 MAX_CASCADE_ERRORS 개까지 보관하고 넘치면 오래된 오류부터 버림
+    pub fn take_errors(&mut self) -> Vec<CascadeError> {
+        self.errors.drain(..).collect()
+    }
+
+    fn push_error(&mut self, error: CascadeError) {
+        if MAX_CASCADE_ERRORS <= self.errors.len() {
+            self.errors.pop_front();
+        }
+
+        self.errors.push_back(error);
+    }
+
+    // 클로저 안에서 emit 된 메시지를 하나의 입력 단위로 묶어 전달
+    // 묶음의 메시지는 모두 먼저 적용된 뒤 메시지마다 순서대로 handler 가 호출되며 Output 에서 같은 batch 로 표시됨
+    // carry 와 future 는 묶이지 않고 그대로 전달됨
+    pub fn transaction<R>(
+        &self, 
+        transaction: impl FnOnce(S::Node<'_>) -> R,
+    ) -> Result<R, EmitError> {
+        let packets: Arc<Mutex<Vec<MessagePacketMessage<S>>>> = Arc::default();
+
+        let consensus = {
+            let packets = packets.clone();
+            let input = Arc::downgrade(&self.input);
+
+            self.consensus.with_callback(move |packet| match packet {
+                MessagePacket::Message(packet) => {
+                    packets.lock().unwrap().push(packet);
+                    Ok(())
+                },
+                packet => input.upgrade().ok_or(EmitError::Closed)?.push(packet),
+            })
+        };
+
+        let result = transaction(consensus.node());
+        let packets = std::mem::take(packets.lock().unwrap().deref_mut());
+
+        if !packets.is_empty() {
+            self.input.push(MessagePacket::Batch(packets))?;
+        }
+
+        Ok(result)
+    }
+
     pub fn try_update(&mut self) -> Output<S> {
         let context = &mut Context::from_waker(noop_waker_ref());
         let mut input: Input<S> = SmallVec::new();
 
-        while let Ok(packet) = self.input_rx.try_recv() {
-            input.push(packet);
-        }
+        self.release();
+        self.input.drain(&mut input);
 
         while let Poll::Ready(Some(message)) = self.future.next().poll_unpin(context) {
             input.push(MessagePacket::Message(message));
@@ -72,23 +493,41 @@ impl<S: System> Component<S> {
     pub async fn update(&mut self) -> Output<S> {   
         let mut input: Input<S> = SmallVec::new();
 
-        select! {            
-            Some(packet) = self.input_rx.recv() => {
-                input.push(packet);
-                while let Ok(packet) = self.input_rx.try_recv() {
-                    input.push(packet);
-                }
-            }
-            Some(packet) = self.future.next() => {
-                let context = &mut Context::from_waker(noop_waker_ref());
+        self.release();
+        self.input.drain(&mut input);
 
-                input.push(MessagePacket::Message(packet));
+        if !input.is_empty() {
+            return self.process(input);
+        }
+
+        loop {
+            let due = self.carry.values().filter_map(|(due, _)| *due).min();
+
+            select! {            
+                _ = self.input.notify.notified() => {
+                    self.input.drain(&mut input);
+                }
+                _ = sleep_until(due.unwrap_or_else(Instant::now)), if due.is_some() => {
+                    self.release();
+                    self.input.drain(&mut input);
+                }
+                Some(packet) = self.future.next() => {
+                    let context = &mut Context::from_waker(noop_waker_ref());
 
-                while let Poll::Ready(Some(packet)) = self.future.next().poll_unpin(context) {
                     input.push(MessagePacket::Message(packet));
+
+                    while let Poll::Ready(Some(packet)) = self.future.next().poll_unpin(context) {
+                        input.push(MessagePacket::Message(packet));
+                    }
+
                 }
+                else => break,
+            }
+
+            // 이미 비워진 입력의 알림만 있었다면 계속 기다림
+            if !input.is_empty() {
+                break;
             }
-            else => {}
         }
 
         self.process(input)
@@ -96,83 +535,303 @@ impl<S: System> Component<S> {
 
     fn process(&mut self, input: Input<S>) -> Output<S> {
         let mut output: Output<S> = SmallVec::new();
+        let now = self.consensus.replica_mut().freeze();
         
         for packet in input {
-            let mut packet = Some(packet);
+            let mut cascade: VecDeque<(MessagePacket<S>, Option<Rc<Chain>>)> = VecDeque::new();
 
-            loop {
-                match packet.take() {
-                    Some(MessagePacket::Message(packet)) => {
-                        if !self.updated.contains(&packet.key) {
-                            self.updated.insert(packet.key);
-        
-                            let message = packet.message.clone();
+            let batch = match packet {
+                MessagePacket::Batch(mut packets) => {
+                    self.batch += 1;
+                    let batch = Some(self.batch);
 
-                            self.consensus.apply(&message);
-        
-                            let delta = packet.instant.map(|instant| instant.elapsed());
+                    if let Some(recording) = &mut self.recording {
+                        recording.input(SessionInput::Batch(
+                            packets.iter()
+                            .map(|packet| packet.message.to_packet(packet.key))
+                            .collect()
+                        ));
+                    }
 
-                            S::handle(
-                                self.process_node(), 
-                                message, 
-                                delta,
-                            );
-        
-                            output.push(packet);
-                        }
-                    },
-                    Some(MessagePacket::Carry(packet)) => {
-                        if !self.updated.contains(&packet.key) {
-                            self.updated.insert(packet.key);
-        
-                            let message = (packet.lookup)();
+                    // 묶음의 메시지를 순서대로 모두 적용한 뒤에 handler 를 호출하여
+                    // handler 가 절반만 적용된 상태를 보지 않도록 함
+                    for packet in packets.iter_mut() {
+                        self.apply(packet);
+                        packet.batch = batch;
+                    }
 
-                            self.consensus.apply(&message);
-        
-                            let delta = Some(packet.instant.elapsed());
+                    let start = output.len();
+                    output.extend(packets);
 
-                            S::handle(
-                                self.process_node(), 
-                                message.clone(), 
-                                delta,
-                            );
-        
-                            output.push(MessagePacketMessage {
-                                key: packet.key,
-                                instant: Some(packet.instant),
-                                message,
-                            });
-                        }
-                    },
-                    Some(MessagePacket::Future(_)) => {
-                        unreachable!();
-                    },
-                    None => break,
-                }                      
-
-                while let Ok(recv) = self.process_rx.try_recv() {
-                    match recv {
-                        MessagePacket::Message(recv) => {
-                            packet = Some(MessagePacket::Message(recv));
-                            break;
-                        },
-                        MessagePacket::Carry(recv) => {
-                            self.carry.insert(recv.key, recv);
-                        },
-                        MessagePacket::Future(recv) => {
-                            self.future.push(recv);
-                        },
+                    // handler 는 메시지마다 순서대로 호출되며
+                    // 각 메시지에서 이어진 연쇄는 따로 입력된 것처럼 중복 제거됨
+                    for index in start..output.len() {
+                        let key = output[index].key;
+                        let message = output[index].message.clone();
+                        let delta = output[index].instant.map(|instant| instant.elapsed());
+
+                        self.updated.insert(key);
+                        S::handle(self.process_node(), message, delta);
+                        self.receive(&mut cascade, Some(Rc::new(Chain { key, depth: 0, parent: None })));
+                        self.cascade(&mut cascade, batch, &mut output);
+                        self.updated.clear();
                     }
-                }
-            }
+
+                    batch
+                },
+                MessagePacket::Future(packet) => {
+                    self.push_future(packet);
+                    continue;
+                },
+                MessagePacket::Carry(packet) => match self.defer(packet) {
+                    Some(packet) => {
+                        cascade.push_back((MessagePacket::Carry(packet), None));
+                        None
+                    },
+                    None => continue,
+                },
+                packet => {
+                    cascade.push_back((packet, None));
+                    None
+                },
+            };
+
+            self.cascade(&mut cascade, batch, &mut output);
 
             self.updated.clear();
+
+            if let Some(history) = &mut self.history {
+                history.close(Grouping::Input);
+            }
+        }
+
+        if let Some(history) = &mut self.history {
+            history.close(Grouping::Tick);
+        }
+
+        if let Some(recording) = &mut self.recording {
+            recording.tick(now, &output);
         }
 
-        for (_, carry) in self.carry.drain() {
-            self.input_tx.send(MessagePacket::Carry(carry)).unwrap();
+        if let Some(journal) = &mut self.journal {
+            let consensus = &self.consensus;
+
+            if let Err(err) = journal.append(&output, || consensus.node().clone_state().unwrap_or_default()) {
+                self.journal_errors.push(err);
+            }
         }
 
+        self.consensus.replica_mut().thaw();
+        self.release();
+        self.prune();
+
         output
     }
-} 
\ No newline at end of file
+
+    // carry 를 적용할 시점, None 이면 바로 적용할 수 있음
+    fn due(&self, carry: &MessagePacketCarry<S>, now: Instant) -> Option<Instant> {
+        match carry.timing {
+            CarryTiming::Next => None,
+            CarryTiming::Debounce(duration) => Some(now + duration),
+            CarryTiming::Throttle(_) => self.throttle.get(&carry.key)
+                .copied()
+                .filter(|end| now < *end),
+        }
+    }
+
+    // 바로 적용할 carry 는 돌려주고 나머지는 같은 Key 의 이전 carry 를 대체하여 보관
+    fn defer(&mut self, mut carry: MessagePacketCarry<S>) -> Option<MessagePacketCarry<S>> {
+        let now = Instant::now();
+
+        match self.due(&carry, now) {
+            Some(due) => {
+                self.carry.insert(carry.key, (Some(due), carry));
+                None
+            },
+            None => {
+                self.start(&mut carry, now);
+                Some(carry)
+            },
+        }
+    }
+
+    // throttle 주기를 시작하고 다시 미뤄지지 않도록 Next 로 바꿈
+    fn start(&mut self, carry: &mut MessagePacketCarry<S>, now: Instant) {
+        if let CarryTiming::Throttle(duration) = carry.timing {
+            self.throttle.insert(carry.key, now + duration);
+        }
+
+        carry.timing = CarryTiming::Next;
+    }
+
+    // 시점이 된 carry 는 입력 큐의 용량과 무관하게 다음 update 로 넘김
+    fn release(&mut self) {
+        let now = Instant::now();
+
+        self.throttle.retain(|_, end| now < *end);
+
+        let keys: SmallVec<[Key; 8]> = self.carry.iter()
+            .filter(|(_, (due, _))| due.is_none_or(|due| due <= now))
+            .map(|(key, _)| *key)
+            .collect();
+
+        for key in keys {
+            if let Some((_, mut carry)) = self.carry.remove(&key) {
+                self.start(&mut carry, now);
+
+                self.input.push_unbounded(
+                    self.input.packets.lock().unwrap(), 
+                    MessagePacket::Carry(carry),
+                ).ok();
+            }
+        }
+    }
+
+    fn apply(&mut self, packet: &MessagePacketMessage<S>) {
+        if !self.restoring {
+            if let Some(history) = &mut self.history {
+                let consensus = &self.consensus;
+                history.record(packet, || consensus.node().clone_state().unwrap_or_default());
+            }
+        }
+
+        self.consensus.apply(&packet.message);
+    }
+
+    // 연쇄의 메시지를 차례로 적용하고 handler 가 이어서 emit 한 메시지를 뒤에 이어 처리함
+    fn cascade(
+        &mut self, 
+        cascade: &mut VecDeque<(MessagePacket<S>, Option<Rc<Chain>>)>,
+        batch: Option<BatchId>,
+        output: &mut Output<S>,
+    ) {
+        while let Some((packet, parent)) = cascade.pop_front() {
+            let key = match &packet {
+                MessagePacket::Message(packet) => packet.key,
+                MessagePacket::Carry(packet) => packet.key,
+                MessagePacket::Future(_) | MessagePacket::Batch(_) => unreachable!(),
+            };
+
+            if self.updated.contains(&key) {
+                // 이미 처리된 Key 가 자신의 연쇄 안에서 다시 emit 되면 순환으로 기록
+                // handler 가 자신의 Key 로 다시 emit 한 경우도 순환이며, 다음 update 에 적용하려면 emit_carry 를 사용
+                if Chain::contains(&parent, &key) {
+                    self.push_error(CascadeError::Cycle { 
+                        chain: Chain::keys(&parent, key), 
+                    });
+                }
+                continue;
+            }
+
+            let depth = parent.as_ref().map(|parent| parent.depth + 1).unwrap_or_default();
+
+            // 연쇄가 너무 깊어지면 이 입력에서 이어진 나머지 메시지를 모두 버림
+            if self.cascade_limit.is_some_and(|limit| limit < depth) {
+                self.push_error(CascadeError::Depth { 
+                    limit: self.cascade_limit.unwrap_or_default(), 
+                    chain: Chain::keys(&parent, key), 
+                });
+                cascade.clear();
+                return;
+            }
+
+            self.updated.insert(key);
+
+            let packet = match packet {
+                MessagePacket::Message(packet) => packet,
+                MessagePacket::Carry(packet) => MessagePacketMessage {
+                    key: packet.key,
+                    instant: Some(packet.instant),
+                    batch: None,
+                    message: (packet.lookup)(),
+                },
+                MessagePacket::Future(_) | MessagePacket::Batch(_) => unreachable!(),
+            };
+
+            let delta = self.delta(&packet, parent.is_none());
+
+            self.apply(&packet);
+            self.handle(MessagePacketMessage { batch, ..packet }, delta, output);
+            self.receive(cascade, Some(Rc::new(Chain { key, depth, parent })));
+        }
+    }
+
+    // 최상위 입력은 기록 중이면 session 에 남기고 replay 중이면 기록된 delta 를 사용
+    fn delta(&mut self, packet: &MessagePacketMessage<S>, input: bool) -> Option<Duration> {
+        let delta = match &mut self.replaying {
+            Some(deltas) if input => deltas.pop_front().flatten(),
+            _ => packet.instant.map(|instant| instant.elapsed()),
+        };
+
+        if let Some(recording) = self.recording.as_mut().filter(|_| input) {
+            recording.input(SessionInput::Message { 
+                packet: packet.message.to_packet(packet.key), 
+                delta,
+            });
+        }
+
+        delta
+    }
+
+    fn handle(
+        &mut self, 
+        packet: MessagePacketMessage<S>, 
+        delta: Option<Duration>,
+        output: &mut Output<S>,
+    ) {
+        S::handle(
+            self.process_node(), 
+            packet.message.clone(), 
+            delta,
+        );
+
+        output.push(packet);
+    }
+
+    // latest future 는 같은 Key 의 이전 future 를 취소함
+    fn push_future(&mut self, future: MessagePacketFuture<S>) {
+        if future.latest {
+            if let Some(previous) = self.latest.insert(future.key, future.handle().clone()) {
+                previous.cancel();
+            }
+        }
+
+        if future.repeating {
+            self.repeating.push(future.handle().clone());
+        }
+
+        self.future.push(future);
+    }
+
+    // 완료되었거나 취소된 future 의 핸들을 버림
+    fn prune(&mut self) {
+        self.latest.retain(|_, handle| !handle.is_finished());
+        self.repeating.retain(|handle| !handle.is_finished());
+    }
+
+    fn receive(
+        &mut self, 
+        cascade: &mut VecDeque<(MessagePacket<S>, Option<Rc<Chain>>)>,
+        chain: Option<Rc<Chain>>,
+    ) {
+        while let Ok(recv) = self.process_rx.try_recv() {
+            match recv {
+                MessagePacket::Message(recv) => {
+                    cascade.push_back((MessagePacket::Message(recv), chain.clone()));
+                },
+                MessagePacket::Carry(recv) => {
+                    let due = self.due(&recv, Instant::now());
+                    self.carry.insert(recv.key, (due, recv));
+                },
+                MessagePacket::Future(recv) => {
+                    self.push_future(recv);
+                },
+                MessagePacket::Batch(recv) => {
+                    for recv in recv {
+                        cascade.push_back((MessagePacket::Message(recv), chain.clone()));
+                    }
+                },
+            }
+        }
+    }
+}
@@ -1,5 +1,10 @@
-use std::{ops::DerefMut, sync::{Arc, RwLock}};
-use crate::ext::*;
+use std::{fmt::Debug, future::Future, ops::DerefMut, pin::Pin, sync::{Arc, RwLock, Weak}};
+use crate::{ext::*, replica::Replica};
+
+// Component 의 입력 큐에 공간이 생겼는지 알려줌
+pub(crate) trait InputSpace: Debug + Send + Sync {
+    fn ready(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct Consensus<CS: System> {
@@ -7,22 +12,26 @@ pub struct Consensus<CS: System> {
     emitter: CS::Emitter,
     transient: Transient,
     consensus: Arc<RwLock<CS>>,
+    replica: Arc<RwLock<Replica>>,
+    space: Option<Weak<dyn InputSpace>>,
 }
 
 impl<CS: System> Consensus<CS> {
     pub fn new(
         state: CS,
-        callback: impl Fn(MessagePacket<CS>) + 'static + Send + Sync,
-        process: impl Fn(MessagePacket<CS>) + 'static + Send + Sync,
+        callback: impl Fn(MessagePacket<CS>) -> Result<(), EmitError> + 'static + Send + Sync,
+        process: impl Fn(MessagePacket<CS>) -> Result<(), EmitError> + 'static + Send + Sync,
     ) -> Self {
         let consensus: Arc<RwLock<CS>> = Arc::default();
         *consensus.write().unwrap() = state;
+        let replica: Arc<RwLock<Replica>> = Arc::default();
 
         Self { 
             accesser: Accesser::new(
                 LookupBuilder { 
                     consist: Consist::default(),
                     consensus: consensus.clone(), 
+                    replica: replica.clone(),
                     lookup: Arc::new(|state, _| state),
                 },
             ),
@@ -35,6 +44,62 @@ impl<CS: System> Consensus<CS> {
             ), 
             transient: Transient::default(),
             consensus, 
+            replica,
+            space: None,
+        }
+    }
+
+    // 같은 상태를 공유하면서 Default 모드의 emit 만 callback 으로 보내는 Consensus
+    pub fn with_callback(
+        &self,
+        callback: impl Fn(MessagePacket<CS>) -> Result<(), EmitError> + 'static + Send + Sync,
+    ) -> Self {
+        Self { 
+            accesser: self.accesser.clone(),
+            emitter: Emitter::new(
+                Callback::new(
+                    Consist::default(), 
+                    Arc::new(callback),
+                    self.emitter.callback().process().clone(),
+                ),
+            ), 
+            transient: self.transient,
+            consensus: self.consensus.clone(), 
+            replica: self.replica.clone(),
+            space: self.space.clone(),
+        }
+    }
+
+    pub fn replica(&self) -> Replica { self.replica.read().unwrap().clone() }
+
+    pub(crate) fn replica_mut(&self) -> std::sync::RwLockWriteGuard<'_, Replica> {
+        self.replica.write().unwrap()
+    }
+
+    pub(crate) fn set_space(&mut self, space: Weak<dyn InputSpace>) {
+        self.space = Some(space);
+    }
+
+    // 입력 큐에 공간이 생길 때까지 기다림
+    // 큐에 제한이 없거나 Component 가 drop 되었다면 바로 끝남
+    pub async fn ready(&self) {
+        if let Some(space) = self.space.as_ref().and_then(Weak::upgrade) {
+            space.ready().await;
+        }
+    }
+
+    // Overflow::Block 인 입력 큐가 가득 차 EmitError::WouldBlock 이 반환되면 공간이 생길 때까지 기다린 뒤 다시 emit
+    // 다시 호출될 수 있으므로 emit 은 값을 옮기지 말고 복제하여 emit 해야 하며
+    // 여러 메시지를 emit 하는 경우 이미 들어간 메시지도 다시 emit 됨
+    pub async fn emit_async<T>(
+        &self,
+        mut emit: impl for<'n> FnMut(CS::Node<'n>) -> Result<T, EmitError>,
+    ) -> Result<T, EmitError> {
+        loop {
+            match emit(self.node()) {
+                Err(EmitError::WouldBlock) => self.ready().await,
+                result => return result,
+            }
         }
     }
 
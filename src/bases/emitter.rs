@@ -1,4 +1,4 @@
-use std::{fmt::Debug, future::Future};
+use std::{fmt::Debug, future::Future, time::Duration};
 use crate::ext::*;
 
 pub trait Emitter<S: State>: Debug + Clone + Send + Sync {
@@ -21,6 +21,19 @@ pub trait Emitter<S: State>: Debug + Clone + Send + Sync {
         );
     }
 
+    fn try_emit(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        state: S,
+    ) -> Result<(), EmitError> {
+        self.callback().try_emit(
+            callback_mode, 
+            transient, 
+            state.into_message()
+        )
+    }
+
     fn emit_carry<F>(
         &self, 
         callback_mode: &CallbackMode, 
@@ -34,17 +47,203 @@ pub trait Emitter<S: State>: Debug + Clone + Send + Sync {
         );
     }
 
+    fn emit_debounced(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        state: S,
+        duration: Duration,
+    ) {
+        let message = state.into_message();
+
+        self.callback().emit_carry_timed(
+            callback_mode, 
+            transient, 
+            CarryTiming::Debounce(duration),
+            move || message.clone(),
+        );
+    }
+
+    fn emit_throttled(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        state: S,
+        duration: Duration,
+    ) {
+        let message = state.into_message();
+
+        self.callback().emit_carry_timed(
+            callback_mode, 
+            transient, 
+            CarryTiming::Throttle(duration),
+            move || message.clone(),
+        );
+    }
+
     fn emit_future<F>(
         &self, 
         callback_mode: &CallbackMode, 
         transient: &Transient, 
         future: F,
-    ) 
+    ) -> FutureHandle
     where F: Future<Output = S::Message> + 'static + Send + Sync {
         self.callback().emit_future(
             callback_mode, 
             transient, 
             future,
-        );
+        )
+    }
+
+    fn emit_future_latest<F>(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        future: F,
+    ) -> FutureHandle
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        self.callback().emit_future_latest(
+            callback_mode, 
+            transient, 
+            future,
+        )
+    }
+
+    fn emit_after(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        duration: Duration,
+        state: S,
+    ) -> FutureHandle {
+        self.callback().emit_after(
+            callback_mode, 
+            transient, 
+            duration,
+            state.into_message(),
+        )
+    }
+
+    fn emit_interval<F>(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        period: Duration,
+        lookup: F,
+    ) -> FutureHandle
+    where F: FnMut() -> S::Message + 'static + Send + Sync {
+        self.callback().emit_interval(
+            callback_mode, 
+            transient, 
+            period,
+            lookup,
+        )
+    }
+
+    fn try_emit_carry<F>(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        lookup: F,
+    ) -> Result<(), EmitError> 
+    where F: Fn() -> S::Message + 'static + Send + Sync {
+        self.callback().try_emit_carry(
+            callback_mode, 
+            transient, 
+            lookup,
+        )
+    }
+
+    fn try_emit_debounced(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        state: S,
+        duration: Duration,
+    ) -> Result<(), EmitError> {
+        let message = state.into_message();
+
+        self.callback().try_emit_carry_timed(
+            callback_mode, 
+            transient, 
+            CarryTiming::Debounce(duration),
+            move || message.clone(),
+        )
+    }
+
+    fn try_emit_throttled(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        state: S,
+        duration: Duration,
+    ) -> Result<(), EmitError> {
+        let message = state.into_message();
+
+        self.callback().try_emit_carry_timed(
+            callback_mode, 
+            transient, 
+            CarryTiming::Throttle(duration),
+            move || message.clone(),
+        )
+    }
+
+    fn try_emit_future<F>(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        future: F,
+    ) -> Result<FutureHandle, EmitError>
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        self.callback().try_emit_future(
+            callback_mode, 
+            transient, 
+            future,
+        )
+    }
+
+    fn try_emit_future_latest<F>(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        future: F,
+    ) -> Result<FutureHandle, EmitError>
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        self.callback().try_emit_future_latest(
+            callback_mode, 
+            transient, 
+            future,
+        )
+    }
+
+    fn try_emit_after(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        duration: Duration,
+        state: S,
+    ) -> Result<FutureHandle, EmitError> {
+        self.callback().try_emit_after(
+            callback_mode, 
+            transient, 
+            duration,
+            state.into_message(),
+        )
+    }
+
+    fn try_emit_interval<F>(
+        &self, 
+        callback_mode: &CallbackMode, 
+        transient: &Transient, 
+        period: Duration,
+        lookup: F,
+    ) -> Result<FutureHandle, EmitError>
+    where F: FnMut() -> S::Message + 'static + Send + Sync {
+        self.callback().try_emit_interval(
+            callback_mode, 
+            transient, 
+            period,
+            lookup,
+        )
     }
 }
\ No newline at end of file
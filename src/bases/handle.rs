@@ -0,0 +1,145 @@
+use std::{ops::Deref, pin::Pin, sync::{Arc, Mutex}, task::{Context, Poll}, time::Duration};
+use futures::stream::Stream;
+use tokio::{select, spawn, sync::{mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender}, watch}, time::timeout};
+use crate::ext::*;
+use super::packet::MessagePacketMessage;
+
+type Subscribers<S> = Arc<Mutex<Option<Vec<UnboundedSender<MessagePacketMessage<S>>>>>>;
+
+// tokio task 에서 update 를 반복하는 Component 의 핸들
+// 복제된 핸들은 같은 상태를 읽고 같은 Component 로 emit 하며
+// 모든 핸들이 drop 되거나 shutdown 되면 남은 입력과 future 를 처리한 뒤 task 가 끝남
+#[derive(Debug, Clone)]
+pub struct ComponentHandle<S: System> {
+    consensus: Consensus<S>,
+    stop: Arc<watch::Sender<bool>>,
+    abort: Arc<watch::Sender<bool>>,
+    done: watch::Receiver<bool>,
+    subscribers: Subscribers<S>,
+}
+
+impl<S: System> Deref for ComponentHandle<S> {
+    type Target = Consensus<S>;
+    fn deref(&self) -> &Self::Target { &self.consensus }
+}
+
+impl<S: System> ComponentHandle<S> {
+    pub fn consensus(&self) -> &Consensus<S> { &self.consensus }
+    pub fn is_finished(&self) -> bool { *self.done.borrow() }
+
+    pub(crate) fn new(component: Component<S>) -> Self {
+        let (stop, stop_rx) = watch::channel(false);
+        let (abort, abort_rx) = watch::channel(false);
+        let (done_tx, done) = watch::channel(false);
+        let subscribers: Subscribers<S> = Arc::new(Mutex::new(Some(Vec::new())));
+
+        let consensus = component.consensus().clone();
+
+        spawn(Self::run(component, stop_rx, abort_rx, done_tx, subscribers.clone()));
+
+        Self {
+            consensus,
+            stop: Arc::new(stop),
+            abort: Arc::new(abort),
+            done,
+            subscribers,
+        }
+    }
+
+    // 구독한 뒤 update 에서 처리된 메시지를 순서대로 내보내며 task 가 끝나면 함께 끝남
+    pub fn output(&self) -> ComponentOutput<S> {
+        let (output_tx, output_rx) = unbounded_channel();
+
+        if let Some(subscribers) = self.subscribers.lock().unwrap().as_mut() {
+            subscribers.push(output_tx);
+        }
+
+        ComponentOutput(output_rx)
+    }
+
+    // 새 입력을 기다리지 않고 남은 입력과 future 를 처리한 뒤 task 가 끝날 때까지 기다림
+    // interval 은 취소되고 debounce 와 throttle 의 carry 는 기다리지 않고 바로 적용되며
+    // 매 update 마다 다시 emit 되는 carry 는 버려짐
+    // 끝나지 않는 future 가 있다면 끝나지 않으므로 shutdown_timeout 이나 abort 를 사용
+    pub async fn shutdown(&self) {
+        self.stop.send_replace(true);
+        self.wait().await;
+    }
+
+    // duration 안에 남은 작업이 끝나지 않으면 abort 하고, 모두 처리되었는지를 반환
+    pub async fn shutdown_timeout(&self, duration: Duration) -> bool {
+        self.stop.send_replace(true);
+
+        if timeout(duration, self.wait()).await.is_ok() {
+            return true;
+        }
+
+        self.abort().await;
+        false
+    }
+
+    // 남은 입력과 future 를 버리고 task 가 끝날 때까지 기다림
+    pub async fn abort(&self) {
+        self.stop.send_replace(true);
+        self.abort.send_replace(true);
+        self.wait().await;
+    }
+
+    async fn wait(&self) {
+        let mut done = self.done.clone();
+        done.wait_for(|done| *done).await.ok();
+    }
+
+    async fn run(
+        mut component: Component<S>,
+        mut stop: watch::Receiver<bool>,
+        mut abort: watch::Receiver<bool>,
+        done: watch::Sender<bool>,
+        subscribers: Subscribers<S>,
+    ) {
+        loop {
+            select! {
+                output = component.update() => Self::publish(&subscribers, output),
+                // 모든 핸들이 drop 되어도 멈춤
+                _ = stop.wait_for(|stop| *stop) => break,
+            }
+        }
+
+        component.cancel_repeating();
+
+        while component.is_pending() {
+            component.flush_timed();
+
+            select! {
+                output = component.update() => Self::publish(&subscribers, output),
+                // 모든 핸들이 drop 된 경우에는 남은 작업을 마저 처리함
+                Ok(_) = abort.wait_for(|abort| *abort) => break,
+            }
+        }
+
+        subscribers.lock().unwrap().take();
+        done.send_replace(true);
+    }
+
+    fn publish(
+        subscribers: &Subscribers<S>,
+        output: impl IntoIterator<Item = MessagePacketMessage<S>>,
+    ) {
+        let mut subscribers = subscribers.lock().unwrap();
+        let Some(subscribers) = subscribers.as_mut() else { return };
+
+        for packet in output {
+            subscribers.retain(|subscriber| subscriber.send(packet.clone()).is_ok());
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ComponentOutput<S: State>(UnboundedReceiver<MessagePacketMessage<S>>);
+
+impl<S: State> Stream for ComponentOutput<S> {
+    type Item = MessagePacketMessage<S>;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().0.poll_recv(cx)
+    }
+}
@@ -0,0 +1,157 @@
+use std::{any::type_name_of_val, collections::{HashMap, HashSet, VecDeque}, sync::Arc};
+use crate::ext::*;
+use super::packet::MessagePacketMessage;
+
+// 되돌리기 기록을 하나로 묶는 단위
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Grouping {
+    // 입력 하나와 그로부터 이어진 연쇄, transaction 은 하나의 입력으로 취급
+    #[default]
+    Input,
+    // update 한번에 처리된 모든 입력
+    Tick,
+}
+
+// 제외된 노드의 Key 에서 현재 상태를 꺼내 그 Key 의 packet 으로 만듦
+#[derive(Clone)]
+struct Excluded {
+    packet: Arc<dyn Fn(Key) -> Option<Packet> + Send + Sync>,
+}
+
+impl std::fmt::Debug for Excluded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Excluded")
+        .field("packet", &type_name_of_val(&self.packet))
+        .finish()
+    }
+}
+
+// 묶음마다 적용 직전의 상태를 보관하여 undo/redo 에 사용
+// 제외된 노드의 메시지는 기록되지 않으며, 되돌린 뒤에 그 노드의 현재 상태를 다시 적용하여 값을 유지함
+#[derive(Debug)]
+pub struct History<S: State> {
+    grouping: Grouping,
+    limit: Option<usize>,
+    excluded: HashMap<Consist, Excluded>,
+    undo: VecDeque<S>,
+    redo: Vec<S>,
+    open: Option<S>,
+    latest_excluded: HashSet<Key>,
+}
+
+impl<S: State> Default for History<S> {
+    fn default() -> Self { Self::new(Grouping::default()) }
+}
+
+impl<S: State> History<S> {
+    pub fn grouping(&self) -> Grouping { self.grouping }
+    pub fn undo_len(&self) -> usize { self.undo.len() }
+    pub fn redo_len(&self) -> usize { self.redo.len() }
+    pub fn can_undo(&self) -> bool { !self.undo.is_empty() }
+    pub fn can_redo(&self) -> bool { !self.redo.is_empty() }
+
+    pub fn new(grouping: Grouping) -> Self {
+        Self {
+            grouping,
+            limit: None,
+            excluded: HashMap::new(),
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            open: None,
+            latest_excluded: HashSet::new(),
+        }
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    // 같은 노드의 모든 alt 를 함께 제외
+    pub fn exclude<'n, N: State>(&mut self, node: &impl Node<'n, N>) {
+        let lookup = node.accesser().lookup().clone();
+
+        self.excluded.insert(*node.consist(), Excluded {
+            packet: Arc::new(move |key| lookup.get(&key.transient())
+                .map(|state| state.into_message().to_packet(key))
+            ),
+        });
+    }
+
+    pub fn is_excluded(&self, key: &Key) -> bool {
+        self.excluded.contains_key(&key.consist())
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.open = None;
+        self.latest_excluded.clear();
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        packet: &MessagePacketMessage<S>,
+        snapshot: impl FnOnce() -> S,
+    ) {
+        if self.is_excluded(&packet.key) {
+            self.latest_excluded.insert(packet.key);
+        } else if self.open.is_none() {
+            self.open = Some(snapshot());
+        }
+    }
+
+    pub(crate) fn close(&mut self, grouping: Grouping) {
+        if self.grouping != grouping {
+            return;
+        }
+
+        if let Some(state) = self.open.take() {
+            self.undo.push_back(state);
+            self.redo.clear();
+
+            if self.limit.is_some_and(|limit| limit < self.undo.len()) {
+                self.undo.pop_front();
+            }
+        }
+    }
+
+    pub(crate) fn undo(&mut self, current: S) -> Option<S> {
+        let state = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(state)
+    }
+
+    pub(crate) fn redo(&mut self, current: S) -> Option<S> {
+        let state = self.redo.pop()?;
+        self.undo.push_back(current);
+        Some(state)
+    }
+
+    // 되돌릴 상태와, 아직 적용되기 전인 현재 상태에서 꺼낸 제외된 노드의 상태
+    // 메시지가 아닌 상태를 적용하므로 Push 같은 메시지가 두번 적용되지 않음
+    pub(crate) fn restore(&self, state: S) -> Vec<MessagePacketMessage<S>> {
+        let excluded: Vec<_> = self.latest_excluded.iter()
+            .filter_map(|key| {
+                let packet = (self.excluded.get(&key.consist())?.packet)(*key)?;
+                let message = S::Message::from_packet(&packet, Key::default(), 0).ok()?;
+                Some(MessagePacketMessage {
+                    key: *key,
+                    instant: None,
+                    batch: None,
+                    message,
+                })
+            })
+            .collect();
+
+        let mut packets = vec![MessagePacketMessage {
+            key: Key::default(),
+            instant: None,
+            batch: None,
+            message: state.into_message(),
+        }];
+
+        packets.extend(excluded);
+        packets
+    }
+}
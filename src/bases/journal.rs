@@ -0,0 +1,220 @@
+use std::{fs::{self, File, OpenOptions}, io::{self, BufWriter, Cursor, Read, Write}, path::{Path, PathBuf}};
+use serde::{Deserialize, Serialize};
+use crate::ext::*;
+use super::packet::MessagePacketMessage;
+
+const SNAPSHOT_FILE: &str = "snapshot.cbor";
+const SNAPSHOT_TEMP_FILE: &str = "snapshot.cbor.tmp";
+const JOURNAL_FILE: &str = "journal.log";
+
+// journal 을 디스크에 동기화하는 시점
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Fsync {
+    // 매 update 마다 동기화
+    #[default]
+    Always,
+    // n 개의 packet 이 기록될 때마다 동기화
+    Every(usize),
+    // 운영체제에 맡김
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JournalPolicy {
+    pub fsync: Fsync,
+    // journal 에 n 개의 packet 이 쌓이면 snapshot 을 쓰고 journal 을 비움
+    pub snapshot_every: Option<usize>,
+}
+
+impl Default for JournalPolicy {
+    fn default() -> Self {
+        Self {
+            fsync: Fsync::default(),
+            snapshot_every: Some(4096),
+        }
+    }
+}
+
+// snapshot 에 포함된 마지막 기록의 순번과 root 상태
+#[derive(Serialize, Deserialize)]
+struct Snapshot<S> {
+    sequence: u64,
+    state: S,
+}
+
+// 적용된 메시지를 Packet 으로 journal 에 이어 쓰고 주기적으로 root 상태의 snapshot 을 씀
+// 각 기록은 u32 LE 길이, u64 LE 순번과 CBOR 로 인코딩된 Packet 으로 이루어지며
+// 기록 도중 중단되어 잘린 마지막 기록은 복구 시 버려짐
+// snapshot 을 쓴 뒤 journal 을 비우기 전에 중단되어도 snapshot 의 순번 이하의 기록은 건너뜀
+#[derive(Debug)]
+pub struct Journal {
+    dir: PathBuf,
+    writer: BufWriter<File>,
+    policy: JournalPolicy,
+    entries: usize,
+    unsynced: usize,
+    sequence: u64,
+}
+
+impl Journal {
+    pub fn dir(&self) -> &Path { &self.dir }
+    pub fn policy(&self) -> JournalPolicy { self.policy }
+    pub fn entries(&self) -> usize { self.entries }
+    pub fn sequence(&self) -> u64 { self.sequence }
+
+    // snapshot 과 journal 을 순서대로 적용하여 상태를 복구하고 이어 쓸 Journal 을 염
+    pub fn recover<S: State>(
+        dir: impl AsRef<Path>,
+        policy: JournalPolicy,
+    ) -> io::Result<(S, Self)> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let Snapshot { sequence: snapshot, mut state } = match fs::read(dir.join(SNAPSHOT_FILE)) {
+            Ok(bytes) => ciborium::from_reader(Cursor::new(bytes))
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Snapshot { 
+                sequence: 0, 
+                state: S::default(), 
+            },
+            Err(err) => return Err(err),
+        };
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .append(true)
+            .open(dir.join(JOURNAL_FILE))?;
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        let mut offset = 0;
+        let mut entries = 0;
+        let mut sequence = snapshot;
+
+        while let Some((entry, packet)) = read_packet(&bytes, &mut offset)? {
+            // 이미 snapshot 에 반영된 기록
+            if entry <= snapshot {
+                continue;
+            }
+
+            let message = S::Message::from_packet(&packet, Key::default(), 0)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+            message.apply_to(&mut state);
+            entries += 1;
+            sequence = entry;
+        }
+
+        // 잘린 마지막 기록을 버림
+        file.set_len(offset as u64)?;
+
+        let mut journal = Self {
+            dir,
+            writer: BufWriter::new(file),
+            policy,
+            entries,
+            unsynced: 0,
+            sequence,
+        };
+
+        // 남은 기록을 snapshot 에 합쳐 journal 을 비움
+        if 0 < offset {
+            journal.compact(&state)?;
+        }
+
+        Ok((state, journal))
+    }
+
+    pub fn append<S: State>(
+        &mut self,
+        packets: &[MessagePacketMessage<S>],
+        state: impl FnOnce() -> S,
+    ) -> io::Result<()> {
+        if packets.is_empty() {
+            return Ok(());
+        }
+
+        for packet in packets {
+            let mut buffer = Vec::new();
+
+            ciborium::into_writer(&packet.message.to_packet(packet.key), &mut buffer)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+            self.sequence += 1;
+
+            self.writer.write_all(&(buffer.len() as u32).to_le_bytes())?;
+            self.writer.write_all(&self.sequence.to_le_bytes())?;
+            self.writer.write_all(&buffer)?;
+        }
+
+        self.writer.flush()?;
+
+        self.entries += packets.len();
+        self.unsynced += packets.len();
+
+        if self.policy.snapshot_every.is_some_and(|every| every <= self.entries) {
+            return self.compact(&state());
+        }
+
+        match self.policy.fsync {
+            Fsync::Always => self.sync(),
+            Fsync::Every(every) if every <= self.unsynced => self.sync(),
+            _ => Ok(()),
+        }
+    }
+
+    // snapshot 을 새로 쓰고 journal 을 비움
+    pub fn compact<S: State>(&mut self, state: &S) -> io::Result<()> {
+        let mut buffer = Vec::new();
+
+        ciborium::into_writer(&Snapshot { sequence: self.sequence, state }, &mut buffer)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+        let temp = self.dir.join(SNAPSHOT_TEMP_FILE);
+        let mut file = File::create(&temp)?;
+        file.write_all(&buffer)?;
+        file.sync_all()?;
+        fs::rename(&temp, self.dir.join(SNAPSHOT_FILE))?;
+
+        // rename 이 디스크에 남도록 디렉터리도 동기화
+        #[cfg(unix)]
+        File::open(&self.dir)?.sync_all()?;
+
+        self.writer.flush()?;
+        self.writer.get_ref().set_len(0)?;
+        self.sync()?;
+
+        self.entries = 0;
+        Ok(())
+    }
+
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        self.writer.get_ref().sync_data()?;
+        self.unsynced = 0;
+        Ok(())
+    }
+}
+
+fn read_packet(bytes: &[u8], offset: &mut usize) -> io::Result<Option<(u64, Packet)>> {
+    let Some(header) = bytes.get(*offset..*offset + 12) else {
+        return Ok(None);
+    };
+
+    let len = u32::from_le_bytes(header[..4].try_into().unwrap()) as usize;
+    let sequence = u64::from_le_bytes(header[4..].try_into().unwrap());
+    let start = *offset + 12;
+
+    let Some(frame) = bytes.get(start..start + len) else {
+        return Ok(None);
+    };
+
+    let packet = ciborium::from_reader(Cursor::new(frame))
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+    *offset = start + len;
+    Ok(Some((sequence, packet)))
+}
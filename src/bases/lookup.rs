@@ -1,16 +1,32 @@
 use std::{any::type_name_of_val, sync::{Arc, RwLock}};
-use crate::ext::*;
+use crate::{ext::*, replica::Replica};
+
+type LookupFn<T> = Arc<dyn Fn(&Transient) -> Option<T> + Send + Sync>;
+type QueryFn<A, T> = Arc<dyn Fn(&Transient, &A) -> Option<T> + Send + Sync>;
+type AccessFn<CS, P> = Arc<dyn Fn(Option<&CS>, Transient) -> Option<&P> + Send + Sync>;
+type ReplicaQueryFn<P, A, T> = fn(Option<&P>, &Replica, &A) -> Option<T>;
 
 #[derive(Clone)]
 pub struct Lookup<T: 'static> {
-    lookup: Arc<dyn Fn(&Transient) -> Option<T> + Send + Sync>,
+    lookup: LookupFn<T>,
+}
+
+pub struct Query<A: 'static, T: 'static> {
+    query: QueryFn<A, T>,
+}
+
+impl<A: 'static, T: 'static> Clone for Query<A, T> {
+    fn clone(&self) -> Self {
+        Self { query: self.query.clone() }
+    }
 }
 
 #[derive(Clone)]
 pub struct LookupBuilder<CS: System, P: State> {
     pub consist: Consist,
     pub consensus: Arc<RwLock<CS>>,
-    pub lookup: Arc<dyn Fn(Option<&CS>, Transient) -> Option<&P> + Send + Sync>,
+    pub replica: Arc<RwLock<Replica>>,
+    pub lookup: AccessFn<CS, P>,
 }
 
 impl<T: 'static + std::fmt::Debug> std::fmt::Debug for Lookup<T> {
@@ -21,19 +37,33 @@ impl<T: 'static + std::fmt::Debug> std::fmt::Debug for Lookup<T> {
     }
 }
 
+impl<A: 'static, T: 'static + std::fmt::Debug> std::fmt::Debug for Query<A, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Query")
+        .field("query", &type_name_of_val(&self.query))
+        .finish()
+    }
+}
+
 impl<T: 'static> Lookup<T> {
     pub fn get(&self, transient: &Transient) -> Option<T> { 
         (self.lookup)(transient) 
     }
 }
 
+impl<A: 'static, T: 'static> Query<A, T> {
+    pub fn get(&self, transient: &Transient, arg: &A) -> Option<T> { 
+        (self.query)(transient, arg) 
+    }
+}
+
 impl<CS: System, P: State> LookupBuilder<CS, P> {
     pub fn access<S: State>(
         &self,
         access: fn(Option<&P>, AltIndex) -> Option<&S>,
         id_delta: IdDelta,
     ) -> LookupBuilder<CS, S> {   
-        let Self { consist, consensus, lookup } = self.clone();
+        let Self { consist, consensus, replica, lookup } = self.clone();
 
         LookupBuilder { 
             consist: consist.access(id_delta, P::NODE_ALT_SIZE), 
@@ -44,6 +74,7 @@ impl<CS: System, P: State> LookupBuilder<CS, P> {
                 )
             }), 
             consensus,
+            replica,
         }     
     }
 
@@ -60,4 +91,38 @@ impl<CS: System, P: State> LookupBuilder<CS, P> {
             }), 
         }     
     }
-}
\ No newline at end of file
+
+    pub fn build_query<A: 'static, T: 'static>(
+        self,
+        query: fn(Option<&P>, &A) -> Option<T>,
+    ) -> Query<A, T> {   
+        let consensus = self.consensus;
+        let lookup = self.lookup;
+
+        Query { 
+            query: Arc::new(move |transient, arg| {
+                query(lookup(Some(&consensus.read().unwrap()), *transient), arg)
+            }), 
+        }     
+    }
+
+    // Component 의 replica id 와 시계를 함께 전달받는 query
+    pub fn build_replica_query<A: 'static, T: 'static>(
+        self,
+        query: ReplicaQueryFn<P, A, T>,
+    ) -> Query<A, T> {   
+        let consensus = self.consensus;
+        let replica = self.replica;
+        let lookup = self.lookup;
+
+        Query { 
+            query: Arc::new(move |transient, arg| {
+                query(
+                    lookup(Some(&consensus.read().unwrap()), *transient), 
+                    &replica.read().unwrap(), 
+                    arg,
+                )
+            }), 
+        }     
+    }
+}
@@ -9,4 +9,8 @@ pub mod consensus;
 pub mod node;
 pub mod system;
 pub mod component;
+pub mod handle;
+pub mod history;
+pub mod journal;
+pub mod session;
 pub mod result;
\ No newline at end of file
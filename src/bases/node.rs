@@ -1,4 +1,4 @@
-use std::{fmt::Debug, future::Future};
+use std::{fmt::Debug, future::Future, time::Duration};
 use crate::ext::*;
 
 pub trait Node<'n, S: State>: Debug + Clone {
@@ -51,6 +51,15 @@ pub trait Node<'n, S: State>: Debug + Clone {
         );
     }
 
+    fn try_emit(&self, state: S) -> Result<(), EmitError> {
+        Emitter::try_emit(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            state,
+        )
+    }
+
     fn emit_carry<F>(&self, lookup: F) 
     where F: Fn() -> S::Message + 'static + Send + Sync {
         Emitter::emit_carry(
@@ -61,14 +70,140 @@ pub trait Node<'n, S: State>: Debug + Clone {
         );
     }
 
-    fn emit_future<F>(&self, future: F) 
+    // 같은 Key 로 duration 동안 다시 emit 되지 않으면 마지막 값을 적용
+    fn emit_debounced(&self, state: S, duration: Duration) {
+        Emitter::emit_debounced(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            state,
+            duration,
+        );
+    }
+
+    // 같은 Key 는 duration 에 한번만 적용하고 그 사이의 마지막 값은 주기가 끝날 때 적용
+    fn emit_throttled(&self, state: S, duration: Duration) {
+        Emitter::emit_throttled(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            state,
+            duration,
+        );
+    }
+
+    fn emit_future<F>(&self, future: F) -> FutureHandle
     where F: Future<Output = S::Message> + 'static + Send + Sync {
         Emitter::emit_future(
             self.emitter(), 
             self.callback_mode(), 
             self.transient(), 
             future,
-        );
+        )
+    }
+
+    // 같은 Key 로 먼저 emit_future_latest 된 future 가 아직 완료되지 않았다면 취소함
+    fn emit_future_latest<F>(&self, future: F) -> FutureHandle
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        Emitter::emit_future_latest(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            future,
+        )
+    }
+
+    fn emit_after(&self, duration: Duration, state: S) -> FutureHandle {
+        Emitter::emit_after(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            duration,
+            state,
+        )
+    }
+
+    // 반환된 핸들로 cancel 할 때까지 period 마다 emit
+    fn emit_interval<F>(&self, period: Duration, lookup: F) -> FutureHandle
+    where F: FnMut() -> S::Message + 'static + Send + Sync {
+        Emitter::emit_interval(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            period,
+            lookup,
+        )
+    }
+
+    fn try_emit_carry<F>(&self, lookup: F) -> Result<(), EmitError>
+    where F: Fn() -> S::Message + 'static + Send + Sync {
+        Emitter::try_emit_carry(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            lookup,
+        )
+    }
+
+    fn try_emit_debounced(&self, state: S, duration: Duration) -> Result<(), EmitError> {
+        Emitter::try_emit_debounced(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            state,
+            duration,
+        )
+    }
+
+    fn try_emit_throttled(&self, state: S, duration: Duration) -> Result<(), EmitError> {
+        Emitter::try_emit_throttled(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            state,
+            duration,
+        )
+    }
+
+    fn try_emit_future<F>(&self, future: F) -> Result<FutureHandle, EmitError>
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        Emitter::try_emit_future(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            future,
+        )
+    }
+
+    fn try_emit_future_latest<F>(&self, future: F) -> Result<FutureHandle, EmitError>
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        Emitter::try_emit_future_latest(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            future,
+        )
+    }
+
+    fn try_emit_after(&self, duration: Duration, state: S) -> Result<FutureHandle, EmitError> {
+        Emitter::try_emit_after(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            duration,
+            state,
+        )
+    }
+
+    fn try_emit_interval<F>(&self, period: Duration, lookup: F) -> Result<FutureHandle, EmitError>
+    where F: FnMut() -> S::Message + 'static + Send + Sync {
+        Emitter::try_emit_interval(
+            self.emitter(), 
+            self.callback_mode(), 
+            self.transient(), 
+            period,
+            lookup,
+        )
     }
 }
 
@@ -1,4 +1,7 @@
-use std::{any::type_name_of_val, future::Future, io::Cursor, ops::{Add, Sub}, pin::Pin, task::{Context, Poll}, time::Instant};
+use std::{any::type_name_of_val, future::Future, io::Cursor, ops::{Add, Sub}, pin::Pin, sync::{atomic::{AtomicBool, Ordering}, Arc}, task::{Context, Poll}, time::Duration};
+use futures::{future::{AbortHandle, Abortable}, stream::{self, Stream, StreamExt}};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::time::Instant;
 use crate::prelude::*;
 
 const ALT_DEPTH_SIZE: usize = 4;
@@ -9,25 +12,25 @@ pub type AltSize = u32;
 pub type IdDelta = u32;
 pub type IdSize = u32;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Key(Consist, Transient);
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Consist(Id, AltDepth);
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Id(u32);
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AltDepth(u32);
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Transient([AltIndex; ALT_DEPTH_SIZE]);
 
-#[derive(Default, Debug, Clone)]
-pub struct Payload(Option<Box<[u8]>>);
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Payload(#[serde(with = "serde_bytes")] Option<Box<[u8]>>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Packet {
     key: Key,
     payload: Payload,
@@ -38,25 +41,68 @@ pub enum MessagePacket<S: State> {
     Message(MessagePacketMessage<S>),
     Carry(MessagePacketCarry<S>),
     Future(MessagePacketFuture<S>),
+    Batch(Vec<MessagePacketMessage<S>>),
 }
 
+pub type BatchId = u64;
+
 #[derive(Debug, Clone)]
 pub struct MessagePacketMessage<S: State>{
     pub key: Key,
     pub instant: Option<Instant>,
+    pub batch: Option<BatchId>,
     pub message: S::Message,
 }
 
 pub struct MessagePacketCarry<S: State>{
     pub key: Key,
     pub instant: Instant,
+    pub timing: CarryTiming,
     pub lookup: Box<dyn Fn() -> S::Message + 'static + Send + Sync>,
 }
 
+// carry 를 적용하는 시점
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CarryTiming {
+    // 다음 update
+    #[default]
+    Next,
+    // 같은 Key 로 duration 동안 다시 emit 되지 않으면 마지막 값을 적용
+    Debounce(Duration),
+    // 같은 Key 는 duration 에 한번만 적용하고 그 사이에 emit 된 마지막 값은 주기가 끝날 때 적용
+    Throttle(Duration),
+}
+
 pub struct MessagePacketFuture<S: State>{
     pub key: Key,
     pub instant: Instant,
-    future: Pin<Box<dyn Future<Output = S::Message> + Send + Sync>>,
+    // 같은 Key 로 먼저 emit 된 latest future 를 취소하고 대체함
+    pub latest: bool,
+    // 취소될 때까지 끝나지 않는 interval 과 같은 Stream
+    pub repeating: bool,
+    handle: FutureHandle,
+    future: Pin<Box<dyn Stream<Item = S::Message> + Send + Sync>>,
+}
+
+// emit 된 future 나 timer 를 취소하는 핸들, 이미 끝난 뒤에는 아무 일도 하지 않음
+#[derive(Debug, Clone)]
+pub struct FutureHandle(AbortHandle, Arc<AtomicBool>);
+
+impl FutureHandle {
+    pub fn cancel(&self) { self.0.abort() }
+    pub fn is_cancelled(&self) -> bool { self.0.is_aborted() }
+
+    // 완료되었거나 취소됨
+    pub fn is_finished(&self) -> bool { 
+        self.1.load(Ordering::Relaxed) || self.is_cancelled() 
+    }
+
+    // 아무것도 emit 하지 않고 이미 취소된 핸들
+    pub(crate) fn cancelled() -> Self {
+        let (handle, _) = AbortHandle::new_pair();
+        handle.abort();
+        Self(handle, Arc::new(AtomicBool::new(true)))
+    }
 }
 
 impl<S: State + std::fmt::Debug> std::fmt::Debug for MessagePacketCarry<S> {
@@ -64,6 +110,7 @@ impl<S: State + std::fmt::Debug> std::fmt::Debug for MessagePacketCarry<S> {
         f.debug_struct("MessagePacketCarry")
         .field("key", &self.key)
         .field("instant", &self.instant)
+        .field("timing", &self.timing)
         .field("lookup", &type_name_of_val(&self.lookup))
         .finish()
     }
@@ -74,23 +121,32 @@ impl<S: State + std::fmt::Debug> std::fmt::Debug for MessagePacketFuture<S> {
         f.debug_struct("MessagePacketFuture")
         .field("key", &self.key)
         .field("instant", &self.instant)
+        .field("latest", &self.latest)
+        .field("repeating", &self.repeating)
+        .field("handle", &self.handle)
         .field("future", &type_name_of_val(&self.future))
         .finish()
     }
 }
 
-impl<S: State> Future for MessagePacketFuture<S> {
-    type Output = MessagePacketMessage<S>;
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let key = self.key;
-        let instant = Some(self.instant);
+// future 는 하나, interval 은 여러 메시지를 내보내는 Stream 이며 취소되면 바로 끝남
+// delta 는 emit 되거나 이전 메시지를 내보낸 시점부터 잼
+impl<S: State> Stream for MessagePacketFuture<S> {
+    type Item = MessagePacketMessage<S>;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let poll = this.future.as_mut().poll_next(cx);
 
-        self.get_mut().future.as_mut().poll(cx)
-        .map(|message| MessagePacketMessage {
-            key,
-            instant, 
+        if let Poll::Ready(None) = poll {
+            this.handle.1.store(true, Ordering::Relaxed);
+        }
+
+        poll.map(|message| message.map(|message| MessagePacketMessage {
+            key: this.key,
+            instant: Some(std::mem::replace(&mut this.instant, Instant::now())), 
+            batch: None,
             message,
-        })
+        }))
     }
 }
 
@@ -99,26 +155,34 @@ impl<S: State> MessagePacket<S> {
         Self::Message(MessagePacketMessage { 
             key, 
             instant: None, 
+            batch: None,
             message, 
         })
     }
 
     pub fn carry<F>(key: Key, lookup: F) -> Self 
+    where F: Fn() -> S::Message + 'static + Send + Sync {
+        Self::carry_timed(key, CarryTiming::Next, lookup)
+    }
+
+    pub fn carry_timed<F>(key: Key, timing: CarryTiming, lookup: F) -> Self 
     where F: Fn() -> S::Message + 'static + Send + Sync {
         Self::Carry(MessagePacketCarry { 
             key, 
             instant: Instant::now(), 
+            timing,
             lookup: Box::new(lookup), 
         })
     }
 
     pub fn future<F>(key: Key, future: F) -> Self 
     where F: Future<Output = S::Message> + 'static + Send + Sync {
-        Self::Future(MessagePacketFuture { 
-            key, 
-            instant: Instant::now(), 
-            future: Box::pin(future), 
-        })
+        Self::Future(MessagePacketFuture::new(key, future, false))
+    }
+
+    pub fn future_latest<F>(key: Key, future: F) -> Self 
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        Self::Future(MessagePacketFuture::new(key, future, true))
     }
 
     pub fn wrap<P: State>(
@@ -128,13 +192,7 @@ impl<S: State> MessagePacket<S> {
     ) -> MessagePacket<P> {        
         match self {
             Self::Message(message) => {
-                let index = message.key.transient().index(alt_depth);
-
-                MessagePacket::Message(MessagePacketMessage { 
-                    key: message.key, 
-                    instant: message.instant, 
-                    message: wrap(index, message.message),
-                })
+                MessagePacket::Message(message.wrap(alt_depth, wrap))
             },
             Self::Carry(message) => {
                 let index = message.key.transient().index(alt_depth);
@@ -142,6 +200,7 @@ impl<S: State> MessagePacket<S> {
                 MessagePacket::Carry(MessagePacketCarry { 
                     key: message.key, 
                     instant: message.instant, 
+                    timing: message.timing,
                     lookup: Box::new(move || wrap(index, (message.lookup)())),
                 })
             },
@@ -151,11 +210,59 @@ impl<S: State> MessagePacket<S> {
                 MessagePacket::Future(MessagePacketFuture { 
                     key: message.key, 
                     instant: message.instant, 
-                    future: Box::pin(async move { 
-                        wrap(index, message.future.await) 
-                    })
+                    latest: message.latest,
+                    repeating: message.repeating,
+                    handle: message.handle,
+                    future: Box::pin(message.future.map(move |message| wrap(index, message))),
                 })
             },
+            Self::Batch(messages) => {
+                MessagePacket::Batch(
+                    messages.into_iter()
+                    .map(|message| message.wrap(alt_depth, wrap))
+                    .collect()
+                )
+            },
+        }
+    }
+}
+
+impl<S: State> MessagePacketFuture<S> {
+    pub fn handle(&self) -> &FutureHandle { &self.handle }
+
+    pub fn new<F>(key: Key, future: F, latest: bool) -> Self 
+    where F: Future<Output = S::Message> + 'static + Send + Sync {
+        Self::from_stream(key, stream::once(future), latest)
+    }
+
+    pub fn from_stream<F>(key: Key, stream: F, latest: bool) -> Self 
+    where F: Stream<Item = S::Message> + 'static + Send + Sync {
+        let (handle, registration) = AbortHandle::new_pair();
+
+        Self { 
+            key, 
+            instant: Instant::now(), 
+            latest,
+            repeating: false,
+            handle: FutureHandle(handle, Arc::default()),
+            future: Box::pin(Abortable::new(stream, registration)), 
+        }
+    }
+}
+
+impl<S: State> MessagePacketMessage<S> {
+    pub fn wrap<P: State>(
+        self,
+        alt_depth: AltDepth,
+        wrap: fn(AltIndex, S::Message) -> P::Message,
+    ) -> MessagePacketMessage<P> {        
+        let index = self.key.transient().index(alt_depth);
+
+        MessagePacketMessage { 
+            key: self.key, 
+            instant: self.instant, 
+            batch: self.batch,
+            message: wrap(index, self.message),
         }
     }
 }
@@ -236,21 +343,44 @@ impl Packet {
             payload, 
         }
     }
+
+    // payload 를 T 로 decode 하고, 손상된 payload 는 panic 대신 PacketError 로 반환
+    pub fn decode<T: DeserializeOwned>(&self, depth: usize) -> crate::bases::result::Result<T> {
+        self.payload.try_to_value().map_err(|err| crate::bases::result::PacketError::new(
+            self.clone(),
+            None,
+            Some(depth),
+            format!("{}: {err}", std::any::type_name::<T>()),
+        ))
+    }
 }
 
 impl Payload {
     pub fn from_state<S: State>(state: &S) -> Self {
+        Self::from_value(state)
+    }
+
+    pub fn to_state<S: State>(&self) -> S {
+        self.to_value()
+    }
+
+    pub fn from_value<T: Serialize + std::fmt::Debug>(value: &T) -> Self {
         let mut buffer = Vec::new();
 
-        ciborium::into_writer(state, &mut buffer)
+        ciborium::into_writer(value, &mut buffer)
         .unwrap_or_else(|err| 
-            panic!("serialize {:#?} into CBOR -> Err({err})", state)
+            panic!("serialize {:#?} into CBOR -> Err({err})", value)
         );
 
         Self(Some(buffer.into_boxed_slice()))
     }
 
-    pub fn to_state<S: State>(&self) -> S {
+    pub fn try_to_value<T: DeserializeOwned>(&self) -> Result<T, String> {
+        let bytes = self.0.as_ref().ok_or_else(|| "empty payload".to_string())?;
+        ciborium::from_reader(Cursor::new(bytes)).map_err(|err| err.to_string())
+    }
+
+    pub fn to_value<T: DeserializeOwned>(&self) -> T {
         ciborium::from_reader(Cursor::new(self.0.as_ref().unwrap()))
         .unwrap_or_else(|err| 
             panic!("deserialize CBOR with {:#?} -> Err({err})", self)
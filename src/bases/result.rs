@@ -36,4 +36,92 @@ impl Display for PacketError {
     }
 }
 
-impl core::error::Error for PacketError {}
\ No newline at end of file
+impl core::error::Error for PacketError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitError {
+    // 메시지를 받을 Component 가 이미 drop 됨
+    Closed,
+    // 입력 큐가 가득 차고 Overflow::Error 정책을 사용 중
+    Full,
+    // 입력 큐가 가득 차고 Overflow::Block 정책이지만 async 문맥이라 기다릴 수 없음
+    WouldBlock,
+    // 메시지가 노드의 크기 제한을 넘어 emit 하지 않음
+    TooLarge { len: usize, max_len: usize },
+}
+
+impl Display for EmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Closed => write!(f, "component is closed"),
+            Self::Full => write!(f, "component input queue is full"),
+            Self::WouldBlock => write!(f, "component input queue is full and blocking is not allowed in async context"),
+            Self::TooLarge { len, max_len } => write!(f, "message length {len} exceeds max_len {max_len}"),
+        }
+    }
+}
+
+impl core::error::Error for EmitError {}
+
+impl EmitError {
+    // 결과를 돌려주지 않는 emit 에서 메시지가 버려졌음을 알림
+    // drop 된 Component 로의 emit 은 무시하며, 그 외에는 debug 빌드에서 panic 하고 release 빌드에서 log 로 기록함
+    pub(crate) fn report(result: std::result::Result<(), EmitError>) {
+        match result {
+            Ok(()) | Err(Self::Closed) => (),
+            #[cfg(debug_assertions)]
+            Err(err) => panic!("emit dropped a message: {err}, use try_emit or Consensus::emit_async to handle it"),
+            #[cfg(not(debug_assertions))]
+            Err(err) => log::warn!("emit dropped a message: {err}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CascadeError {
+    // 하나의 입력에서 이어진 연쇄가 limit 보다 깊어져 나머지 연쇄를 버림
+    Depth { limit: usize, chain: Vec<Key> },
+    // 연쇄 안에서 이미 처리된 Key 로 다시 emit 되어 무시됨
+    // handler 가 자신의 Key 로 emit 한 경우도 포함하며 이때 chain 은 같은 Key 로 끝남
+    Cycle { chain: Vec<Key> },
+}
+
+impl Display for CascadeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Depth { limit, chain } => write!(f, 
+                "cascade exceeded depth limit {limit} chain:{chain:?}",
+            ),
+            Self::Cycle { chain } => write!(f, 
+                "cascade cycle chain:{chain:?}",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for CascadeError {}
+
+#[derive(Debug, Clone)]
+pub enum ReplayError {
+    // 기록된 Packet 을 메시지로 변환하지 못함
+    Packet(PacketError),
+    // tick 번째 update 의 Output 이 기록과 다름
+    Mismatch { tick: usize, expected: Vec<Packet>, actual: Vec<Packet> },
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Packet(err) => write!(f, "replay packet error {err}"),
+            Self::Mismatch { tick, expected, actual } => write!(f, 
+                "replay output mismatch tick:{tick} expected:{expected:?} actual:{actual:?}",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ReplayError {}
+
+impl From<PacketError> for ReplayError {
+    fn from(err: PacketError) -> Self { Self::Packet(err) }
+}
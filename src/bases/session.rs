@@ -0,0 +1,181 @@
+use std::{fs, io::{self, Cursor}, ops::{Deref, DerefMut}, path::Path, sync::{atomic::{AtomicU64, Ordering}, Arc}, time::{Duration, Instant}};
+use serde::{Deserialize, Serialize};
+use crate::{ext::*, replica::wall_clock};
+use super::packet::MessagePacketMessage;
+
+// update 에서 처리된 최상위 입력
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionInput {
+    // emit 된 메시지, carry 의 lookup 결과, future 의 결과와 handler 에 전달된 delta
+    Message { packet: Packet, delta: Option<Duration> },
+    Batch(Vec<Packet>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionTick {
+    // 기록을 시작한 뒤 update 가 끝난 시점
+    pub at: Duration,
+    // update 동안 고정된 Component 의 시계 값
+    pub clock: u64,
+    pub inputs: Vec<SessionInput>,
+    pub outputs: Vec<Packet>,
+}
+
+// 기록을 시작할 때의 root 상태와 replica id, 이후 입력이 있었던 update 의 목록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session<S> {
+    pub state: S,
+    pub replica: ReplicaId,
+    pub ticks: Vec<SessionTick>,
+}
+
+impl<S: State> Session<S> {
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut buffer = Vec::new();
+
+        ciborium::into_writer(self, &mut buffer)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+        fs::write(path, buffer)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        ciborium::from_reader(Cursor::new(fs::read(path)?))
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Recording {
+    start: Instant,
+    inputs: Vec<SessionInput>,
+    ticks: Vec<SessionTick>,
+}
+
+impl Recording {
+    pub(crate) fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            inputs: Vec::new(),
+            ticks: Vec::new(),
+        }
+    }
+
+    pub(crate) fn ticks(&self) -> &[SessionTick] { &self.ticks }
+
+    pub(crate) fn input(&mut self, input: SessionInput) {
+        self.inputs.push(input);
+    }
+
+    pub(crate) fn tick<S: State>(&mut self, clock: u64, output: &[MessagePacketMessage<S>]) {
+        if self.inputs.is_empty() {
+            return;
+        }
+
+        self.ticks.push(SessionTick {
+            at: self.start.elapsed(),
+            clock,
+            inputs: std::mem::take(&mut self.inputs),
+            outputs: output.iter()
+                .map(|packet| packet.message.to_packet(packet.key))
+                .collect(),
+        });
+    }
+}
+
+// Component 를 감싸 모든 update 의 입력과 Output 을 Session 으로 기록
+#[derive(Debug)]
+pub struct Recorder<S: System> {
+    component: Component<S>,
+    state: S,
+}
+
+impl<S: System> Deref for Recorder<S> {
+    type Target = Component<S>;
+    fn deref(&self) -> &Self::Target { &self.component }
+}
+
+impl<S: System> DerefMut for Recorder<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.component }
+}
+
+impl<S: System> Recorder<S> {
+    pub fn new(mut component: Component<S>) -> Self {
+        let state = component.node().clone_state().unwrap_or_default();
+        component.start_recording();
+
+        Self { component, state }
+    }
+
+    pub fn session(&self) -> Session<S> {
+        Session {
+            state: self.state.clone(),
+            replica: self.component.replica().id(),
+            ticks: self.component.recorded().to_vec(),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.session().save(path)
+    }
+
+    pub fn into_inner(mut self) -> (Component<S>, Session<S>) {
+        let session = self.session();
+        self.component.stop_recording();
+
+        (self.component, session)
+    }
+}
+
+// Session 을 새 Component 에 순서대로 다시 입력하고 매 update 의 Output 을 기록과 비교
+// handler 가 남긴 carry 와 future 는 버리고 기록된 결과를 대신 입력함
+// replica id 와 매 update 의 시계 값도 기록된 값을 사용함
+#[derive(Debug, Clone)]
+pub struct Replayer<S: System> {
+    session: Session<S>,
+}
+
+impl<S: System> Replayer<S> {
+    pub fn session(&self) -> &Session<S> { &self.session }
+
+    pub fn new(session: Session<S>) -> Self {
+        Self { session }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(Session::load(path)?))
+    }
+
+    pub fn replay(&self) -> core::result::Result<Component<S>, ReplayError> {
+        let clock = Arc::new(AtomicU64::new(0));
+
+        let mut component = Component::new(self.session.state.clone())
+            .with_replica(self.session.replica);
+
+        component.set_clock({
+            let clock = clock.clone();
+            Arc::new(move || clock.load(Ordering::Relaxed))
+        });
+
+        for (tick, recorded) in self.session.ticks.iter().enumerate() {
+            clock.store(recorded.clock, Ordering::Relaxed);
+
+            let actual: Vec<Packet> = component.replay(&recorded.inputs)?
+                .iter()
+                .map(|packet| packet.message.to_packet(packet.key))
+                .collect();
+
+            if actual != recorded.outputs {
+                return Err(ReplayError::Mismatch {
+                    tick,
+                    expected: recorded.outputs.clone(),
+                    actual,
+                });
+            }
+        }
+
+        component.set_clock(Arc::new(wall_clock));
+
+        Ok(component)
+    }
+}
@@ -9,6 +9,7 @@ pub trait Fallback: State {
 }
 
 pub trait System: Fallback {
+    #[allow(clippy::match_single_binding)]
     fn handle(
         node: Self::Node<'_>, 
         message: Self::Message, 
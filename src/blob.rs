@@ -0,0 +1,398 @@
+use std::ops::Range;
+use serde::{Deserialize, Serialize};
+use serde_bytes::{ByteBuf, Bytes};
+use crate::ext::*;
+
+pub const DEFAULT_CHUNK_SIZE: usize = 16 * 1024;
+pub const DEFAULT_MAX_LEN: usize = 64 * 1024 * 1024;
+
+// emit 되는 Replace 와 Patch 는 chunk_size 단위로 나뉘어 전달됨
+// max_len 을 넘는 Replace 와 Patch 는 무시되며
+// emit_replace 와 emit_patch 는 chunk 를 보내기 전에 EmitError::TooLarge 로 거부함
+// chunk 번호를 담기 위해 alt depth 를 하나 사용하므로 Signal 과 같이 중첩 깊이 4 단계 중 하나를 차지함
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Blob {
+    #[serde(with = "serde_bytes")]
+    bytes: Vec<u8>,
+    chunk_size: usize,
+    #[serde(default = "default_max_len")]
+    max_len: usize,
+}
+
+fn default_max_len() -> usize { DEFAULT_MAX_LEN }
+
+impl Default for Blob {
+    fn default() -> Self {
+        Self {
+            bytes: Vec::new(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            max_len: DEFAULT_MAX_LEN,
+        }
+    }
+}
+
+impl Blob {
+    pub fn bytes(&self) -> &[u8] { &self.bytes }
+    pub fn chunk_size(&self) -> usize { self.chunk_size }
+    pub fn max_len(&self) -> usize { self.max_len }
+    pub fn len(&self) -> usize { self.bytes.len() }
+    pub fn is_empty(&self) -> bool { self.bytes.is_empty() }
+
+    pub fn new(bytes: impl Into<Vec<u8>>) -> Self {
+        Self {
+            bytes: bytes.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    // 적용되지 않았으면 false
+    pub fn replace(&mut self, bytes: &[u8]) -> bool {
+        if self.max_len < bytes.len() {
+            return false;
+        }
+
+        self.bytes.clear();
+        self.bytes.extend_from_slice(bytes);
+        true
+    }
+
+    // 끝 위치가 usize 를 넘거나 max_len 을 넘으면 적용하지 않고 false
+    pub fn patch(&mut self, offset: usize, bytes: &[u8]) -> bool {
+        let Some(end) = offset.checked_add(bytes.len()) else { return false };
+
+        if self.max_len < end {
+            return false;
+        }
+
+        if self.bytes.len() < end {
+            self.bytes.resize(end, 0);
+        }
+
+        self.bytes[offset..end].copy_from_slice(bytes);
+        true
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.bytes.truncate(len);
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod blob {
+    use super::*;
+
+    const REPLACE_ID_DELTA: super::IdDelta = 1;
+    const REPLACE_ID_DELTA_END: super::IdDelta = REPLACE_ID_DELTA + 1;
+    const PATCH_ID_DELTA: super::IdDelta = REPLACE_ID_DELTA_END;
+    const PATCH_ID_DELTA_END: super::IdDelta = PATCH_ID_DELTA + 1;
+    const TRUNCATE_ID_DELTA: super::IdDelta = PATCH_ID_DELTA_END;
+    const TRUNCATE_ID_DELTA_END: super::IdDelta = TRUNCATE_ID_DELTA + 1;
+
+    #[derive(Debug, Clone)]
+    pub enum Message {
+        Replace(Vec<u8>),
+        Patch { offset: usize, bytes: Vec<u8> },
+        Truncate(usize),
+        State(Blob),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter {
+        callback: super::Callback<Blob>,
+        pub replace: super::Callback<Blob>,
+        pub patch: super::Callback<Blob>,
+        pub truncate: super::Callback<Blob>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser {
+        lookup: super::Lookup<Blob>,
+        lookup_len: super::Lookup<usize>,
+        lookup_chunk_size: super::Lookup<usize>,
+        lookup_max_len: super::Lookup<usize>,
+        query_read: super::Query<Range<usize>, Vec<u8>>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n> {
+        accesser: &'n Accesser,
+        emitter: &'n Emitter,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+    }
+
+    impl super::State for Blob {
+        const NODE_SIZE: super::IdSize = TRUNCATE_ID_DELTA_END;
+        const NODE_ALT_SIZE: super::AltSize = 1;
+
+        type Message = blob::Message;
+        type Emitter = blob::Emitter;
+        type Accesser = blob::Accesser;
+        type Node<'n> = blob::Node<'n>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            blob::Message::State(self)
+        }
+    }
+
+    impl super::Fallback for Blob {
+        fn fallback(
+            _node: Node<'_>,
+            message: Message,
+            _delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::Replace(_) => (),
+                Message::Patch { .. } => (),
+                Message::Truncate(_) => (),
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl super::System for Blob {
+
+    }
+
+    impl super::Message for Message {
+        type State = Blob;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    REPLACE_ID_DELTA..REPLACE_ID_DELTA_END => Ok(Message::Replace(
+                        packet.decode::<ByteBuf>(depth)?.into_vec()
+                    )),
+                    PATCH_ID_DELTA..PATCH_ID_DELTA_END => {
+                        let (offset, bytes) = packet.decode::<(usize, ByteBuf)>(depth)?;
+                        Ok(Message::Patch { offset, bytes: bytes.into_vec() })
+                    },
+                    TRUNCATE_ID_DELTA..TRUNCATE_ID_DELTA_END => Ok(Message::Truncate(
+                        packet.decode(depth)?
+                    )),
+                    id_delta => Err(super::PacketError::new(
+                        packet.clone(),
+                        Some(id_delta),
+                        Some(depth),
+                        format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                    )),
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::Replace(bytes) => super::Packet::new(key, super::Payload::from_value(&Bytes::new(bytes))),
+                Self::Patch { offset, bytes } => super::Packet::new(key, super::Payload::from_value(&(offset, Bytes::new(bytes)))),
+                Self::Truncate(len) => super::Packet::new(key, super::Payload::from_value(len)),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Blob) {
+            match self {
+                Self::Replace(bytes) => { state.replace(bytes); },
+                Self::Patch { offset, bytes } => { state.patch(*offset, bytes); },
+                Self::Truncate(len) => state.truncate(*len),
+                Self::State(new_state) => *state = new_state.clone(),
+            }
+        }
+    }
+
+    impl super::Emitter<Blob> for Emitter {
+        fn callback(&self) -> &super::Callback<Blob> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Blob>) -> Self {
+            Self {
+                replace: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    REPLACE_ID_DELTA,
+                    |_, message| message,
+                ),
+                patch: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    PATCH_ID_DELTA,
+                    |_, message| message,
+                ),
+                truncate: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    TRUNCATE_ID_DELTA,
+                    |_, message| message,
+                ),
+                callback,
+            }
+        }
+    }
+
+    impl super::Accesser<Blob> for Accesser {
+        fn lookup(&self) -> &super::Lookup<Blob> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: super::LookupBuilder<CS, Blob>) -> Self {
+            Self {
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_len: builder.clone().build(|state| state.map(|state| state.len())),
+                lookup_chunk_size: builder.clone().build(|state| state.map(|state| state.chunk_size())),
+                lookup_max_len: builder.clone().build(|state| state.map(|state| state.max_len())),
+                query_read: builder.build_query(|state, range| {
+                    state.and_then(|state| state.bytes().get(range.clone())).map(<[u8]>::to_vec)
+                }),
+            }
+        }
+    }
+
+    impl<'n> super::Node<'n, Blob> for Node<'n> {
+        fn accesser(&self) -> &Accesser { self.accesser }
+        fn emitter(&self) -> &Emitter { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n> super::NewNode<'n, Blob> for Node<'n> {
+        fn new(
+            accesser: &'n Accesser,
+            emitter: &'n Emitter,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+            }
+        }
+    }
+
+    impl<'n> Node<'n> {
+        pub fn emit_replace(&self, bytes: &[u8]) {
+            EmitError::report(self.try_emit_replace(bytes));
+        }
+
+        pub fn try_emit_replace(&self, bytes: &[u8]) -> Result<(), EmitError> {
+            let max_len = self.max_len();
+
+            // 일부 chunk 만 적용되어 잘린 Blob 이 남지 않도록 미리 거부
+            if max_len < bytes.len() {
+                return Err(EmitError::TooLarge { len: bytes.len(), max_len });
+            }
+
+            let chunk_size = self.chunk_size();
+            let head = bytes.len().min(chunk_size);
+
+            self.emitter.replace.try_emit(
+                self.callback_mode,
+                self.transient,
+                Message::Replace(bytes[..head].to_vec()),
+            )?;
+
+            self.try_emit_patch(head, &bytes[head..])
+        }
+
+        pub fn emit_patch(&self, offset: usize, bytes: &[u8]) {
+            EmitError::report(self.try_emit_patch(offset, bytes));
+        }
+
+        pub fn try_emit_patch(&self, offset: usize, bytes: &[u8]) -> Result<(), EmitError> {
+            use crate::ext::Node;
+
+            let max_len = self.max_len();
+            let len = offset.saturating_add(bytes.len());
+
+            if max_len < len {
+                return Err(EmitError::TooLarge { len, max_len });
+            }
+
+            let chunk_size = self.chunk_size();
+
+            for (index, chunk) in bytes.chunks(chunk_size).enumerate() {
+                // 적용될 수 없는 chunk 는 emit 하지 않음
+                let Some(offset) = index.checked_mul(chunk_size)
+                    .and_then(|delta| offset.checked_add(delta))
+                    .filter(|offset| offset.checked_add(chunk.len()).is_some())
+                else { break };
+
+                // 같은 Tick 에 여러 chunk 가 중복 제거되지 않도록 chunk 번호를 alt index 로 사용
+                let transient = self.transient.alt(
+                    self.consist().alt_depth(),
+                    (offset / chunk_size) as AltIndex,
+                );
+
+                self.emitter.patch.try_emit(
+                    self.callback_mode,
+                    &transient,
+                    Message::Patch { offset, bytes: chunk.to_vec() },
+                )?;
+            }
+
+            Ok(())
+        }
+
+        pub fn emit_truncate(&self, len: usize) {
+            EmitError::report(self.try_emit_truncate(len));
+        }
+
+        pub fn try_emit_truncate(&self, len: usize) -> Result<(), EmitError> {
+            self.emitter.truncate.try_emit(self.callback_mode, self.transient, Message::Truncate(len))
+        }
+
+        pub fn len(&self) -> usize {
+            self.accesser.lookup_len.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        pub fn chunk_size(&self) -> usize {
+            self.accesser.lookup_chunk_size.get(self.transient).unwrap_or(DEFAULT_CHUNK_SIZE).max(1)
+        }
+
+        pub fn max_len(&self) -> usize {
+            self.accesser.lookup_max_len.get(self.transient).unwrap_or(DEFAULT_MAX_LEN)
+        }
+
+        pub fn read(&self, range: Range<usize>) -> Option<Vec<u8>> {
+            self.accesser.query_read.get(self.transient, &range)
+        }
+    }
+}
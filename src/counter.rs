@@ -0,0 +1,318 @@
+use std::{collections::BTreeMap, sync::Mutex};
+use serde::{Deserialize, Serialize};
+use crate::ext::*;
+
+// PN-Counter
+// 각 replica 의 누적 증가량과 감소량을 따로 보관하고 큰 값을 취하여 병합하므로
+// 메시지가 어떤 순서로, 몇 번 적용되더라도 같은 값으로 수렴함
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Counter {
+    increments: BTreeMap<ReplicaId, u64>,
+    decrements: BTreeMap<ReplicaId, u64>,
+    // 아직 적용되지 않은 emit 을 포함하여 replica 별로 발행한 누적량
+    #[serde(skip)]
+    issued_increment: Mutex<BTreeMap<ReplicaId, u64>>,
+    #[serde(skip)]
+    issued_decrement: Mutex<BTreeMap<ReplicaId, u64>>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Count {
+    pub replica: ReplicaId,
+    pub total: u64,
+}
+
+impl Clone for Counter {
+    fn clone(&self) -> Self {
+        Self {
+            increments: self.increments.clone(),
+            decrements: self.decrements.clone(),
+            issued_increment: Mutex::new(self.issued_increment.lock().unwrap().clone()),
+            issued_decrement: Mutex::new(self.issued_decrement.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl Counter {
+    // i64 범위를 넘으면 i64::MIN 이나 i64::MAX 로 포화됨
+    pub fn value(&self) -> i64 {
+        let increments: i128 = self.increments.values().map(|total| *total as i128).sum();
+        let decrements: i128 = self.decrements.values().map(|total| *total as i128).sum();
+        (increments - decrements).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    // node 에서 emit 할 때는 Component 의 replica id 로 발행
+    pub fn prepare_increment(&self, replica: ReplicaId, n: u64) -> Count {
+        Self::prepare(&self.increments, &self.issued_increment, replica, n)
+    }
+
+    pub fn prepare_decrement(&self, replica: ReplicaId, n: u64) -> Count {
+        Self::prepare(&self.decrements, &self.issued_decrement, replica, n)
+    }
+
+    pub fn apply_increment(&mut self, count: &Count) {
+        Self::apply(&mut self.increments, count);
+    }
+
+    pub fn apply_decrement(&mut self, count: &Count) {
+        Self::apply(&mut self.decrements, count);
+    }
+
+    pub fn merge(&mut self, other: &Counter) {
+        for (replica, total) in &other.increments {
+            Self::apply(&mut self.increments, &Count { replica: *replica, total: *total });
+        }
+
+        for (replica, total) in &other.decrements {
+            Self::apply(&mut self.decrements, &Count { replica: *replica, total: *total });
+        }
+    }
+
+    fn prepare(
+        totals: &BTreeMap<ReplicaId, u64>,
+        issued: &Mutex<BTreeMap<ReplicaId, u64>>,
+        replica: ReplicaId,
+        n: u64,
+    ) -> Count {
+        let total = totals.get(&replica).copied().unwrap_or_default();
+
+        let mut issued = issued.lock().unwrap();
+        let issued = issued.entry(replica).or_default();
+        *issued = (*issued).max(total).saturating_add(n);
+
+        Count {
+            replica,
+            total: *issued,
+        }
+    }
+
+    fn apply(totals: &mut BTreeMap<ReplicaId, u64>, count: &Count) {
+        let total = totals.entry(count.replica).or_default();
+        *total = (*total).max(count.total);
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod counter {
+    use super::*;
+
+    const INCREMENT_ID_DELTA: super::IdDelta = 1;
+    const INCREMENT_ID_DELTA_END: super::IdDelta = INCREMENT_ID_DELTA + 1;
+    const DECREMENT_ID_DELTA: super::IdDelta = INCREMENT_ID_DELTA_END;
+    const DECREMENT_ID_DELTA_END: super::IdDelta = DECREMENT_ID_DELTA + 1;
+
+    #[derive(Debug, Clone)]
+    pub enum Message {
+        Increment(Count),
+        Decrement(Count),
+        State(Counter),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter {
+        callback: super::Callback<Counter>,
+        pub increment: super::Callback<Counter>,
+        pub decrement: super::Callback<Counter>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser {
+        lookup: super::Lookup<Counter>,
+        lookup_value: super::Lookup<i64>,
+        query_increment: super::Query<u64, Count>,
+        query_decrement: super::Query<u64, Count>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n> {
+        accesser: &'n Accesser,
+        emitter: &'n Emitter,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+    }
+
+    impl super::State for Counter {
+        const NODE_SIZE: super::IdSize = DECREMENT_ID_DELTA_END;
+        const NODE_ALT_SIZE: super::AltSize = 0;
+
+        type Message = counter::Message;
+        type Emitter = counter::Emitter;
+        type Accesser = counter::Accesser;
+        type Node<'n> = counter::Node<'n>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            counter::Message::State(self)
+        }
+    }
+
+    impl super::Fallback for Counter {
+        fn fallback(
+            _node: Node<'_>,
+            message: Message,
+            _delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::Increment(_) => (),
+                Message::Decrement(_) => (),
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl super::System for Counter {
+
+    }
+
+    impl super::Message for Message {
+        type State = Counter;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    INCREMENT_ID_DELTA..INCREMENT_ID_DELTA_END => Ok(Message::Increment(
+                        packet.decode(depth)?
+                    )),
+                    DECREMENT_ID_DELTA..DECREMENT_ID_DELTA_END => Ok(Message::Decrement(
+                        packet.decode(depth)?
+                    )),
+                    id_delta => Err(super::PacketError::new(
+                        packet.clone(),
+                        Some(id_delta),
+                        Some(depth),
+                        format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                    )),
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::Increment(count) => super::Packet::new(key, super::Payload::from_value(count)),
+                Self::Decrement(count) => super::Packet::new(key, super::Payload::from_value(count)),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Counter) {
+            match self {
+                Self::Increment(count) => state.apply_increment(count),
+                Self::Decrement(count) => state.apply_decrement(count),
+                Self::State(new_state) => state.merge(new_state),
+            }
+        }
+    }
+
+    impl super::Emitter<Counter> for Emitter {
+        fn callback(&self) -> &super::Callback<Counter> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Counter>) -> Self {
+            Self {
+                increment: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    INCREMENT_ID_DELTA,
+                    |_, message| message,
+                ),
+                decrement: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    DECREMENT_ID_DELTA,
+                    |_, message| message,
+                ),
+                callback,
+            }
+        }
+    }
+
+    impl super::Accesser<Counter> for Accesser {
+        fn lookup(&self) -> &super::Lookup<Counter> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: super::LookupBuilder<CS, Counter>) -> Self {
+            Self {
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_value: builder.clone().build(|state| state.map(|state| state.value())),
+                query_increment: builder.clone().build_replica_query(|state, replica, n| {
+                    state.map(|state| state.prepare_increment(replica.id(), *n))
+                }),
+                query_decrement: builder.build_replica_query(|state, replica, n| {
+                    state.map(|state| state.prepare_decrement(replica.id(), *n))
+                }),
+            }
+        }
+    }
+
+    impl<'n> super::Node<'n, Counter> for Node<'n> {
+        fn accesser(&self) -> &Accesser { self.accesser }
+        fn emitter(&self) -> &Emitter { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n> super::NewNode<'n, Counter> for Node<'n> {
+        fn new(
+            accesser: &'n Accesser,
+            emitter: &'n Emitter,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+            }
+        }
+    }
+
+    impl<'n> Node<'n> {
+        pub fn emit_increment(&self, n: u64) {
+            EmitError::report(self.try_emit_increment(n));
+        }
+
+        pub fn try_emit_increment(&self, n: u64) -> Result<(), EmitError> {
+            if let Some(count) = self.accesser.query_increment.get(self.transient, &n) {
+                self.emitter.increment.try_emit(self.callback_mode, self.transient, Message::Increment(count))?;
+            }
+
+            Ok(())
+        }
+
+        pub fn emit_decrement(&self, n: u64) {
+            EmitError::report(self.try_emit_decrement(n));
+        }
+
+        pub fn try_emit_decrement(&self, n: u64) -> Result<(), EmitError> {
+            if let Some(count) = self.accesser.query_decrement.get(self.transient, &n) {
+                self.emitter.decrement.try_emit(self.callback_mode, self.transient, Message::Decrement(count))?;
+            }
+
+            Ok(())
+        }
+
+        pub fn value(&self) -> i64 {
+            self.accesser.lookup_value.get(self.transient).unwrap_or_default()
+        }
+    }
+}
@@ -0,0 +1,414 @@
+use std::{collections::VecDeque, vec::IntoIter};
+use serde::{Deserialize, Serialize};
+use crate::ext::*;
+
+// items 의 각 항목은 front 로부터의 논리 index 를 가지며
+// push, pop, 용량 초과로 인한 제거가 일어나도 남은 항목의 index 는 바뀌지 않음
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Deque<I> {
+    items: VecDeque<I>,
+    front: AltIndex,
+    capacity: Option<AltSize>,
+}
+
+impl<I> Deque<I> {
+    pub fn items(&self) -> &VecDeque<I> { &self.items }
+    pub fn front_index(&self) -> AltIndex { self.front }
+    pub fn back_index(&self) -> AltIndex { self.front.wrapping_add(self.len() as AltIndex) }
+    pub fn capacity(&self) -> Option<AltSize> { self.capacity }
+    pub fn len(&self) -> usize { self.items.len() }
+    pub fn is_empty(&self) -> bool { self.items.is_empty() }
+
+    pub fn new() -> Self {
+        Self {
+            items: VecDeque::new(),
+            front: 0,
+            capacity: None,
+        }
+    }
+
+    pub fn bounded(capacity: AltSize) -> Self {
+        Self {
+            items: VecDeque::with_capacity(capacity as usize),
+            front: 0,
+            capacity: Some(capacity),
+        }
+    }
+
+    pub fn indices(&self) -> impl Iterator<Item = AltIndex> {
+        let front = self.front;
+        (0..self.len() as AltIndex).map(move |position| front.wrapping_add(position))
+    }
+
+    pub fn get(&self, index: AltIndex) -> Option<&I> {
+        self.items.get(index.wrapping_sub(self.front) as usize)
+    }
+
+    pub fn get_mut(&mut self, index: AltIndex) -> Option<&mut I> {
+        self.items.get_mut(index.wrapping_sub(self.front) as usize)
+    }
+
+    pub fn push_back(&mut self, item: I) {
+        match self.capacity {
+            Some(0) => return,
+            Some(capacity) if self.len() >= capacity as usize => { self.pop_front(); },
+            _ => (),
+        }
+
+        self.items.push_back(item);
+    }
+
+    pub fn push_front(&mut self, item: I) {
+        match self.capacity {
+            Some(0) => return,
+            Some(capacity) if self.len() >= capacity as usize => { self.pop_back(); },
+            _ => (),
+        }
+
+        self.front = self.front.wrapping_sub(1);
+        self.items.push_front(item);
+    }
+
+    pub fn pop_front(&mut self) -> Option<I> {
+        let item = self.items.pop_front()?;
+        self.front = self.front.wrapping_add(1);
+        Some(item)
+    }
+
+    pub fn pop_back(&mut self) -> Option<I> {
+        self.items.pop_back()
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod deque {
+    use super::*;
+
+    const PUSH_BACK_ID_DELTA: super::IdDelta = 1;
+    const PUSH_BACK_ID_DELTA_END: super::IdDelta = PUSH_BACK_ID_DELTA + 1;
+    const PUSH_FRONT_ID_DELTA: super::IdDelta = PUSH_BACK_ID_DELTA_END;
+    const PUSH_FRONT_ID_DELTA_END: super::IdDelta = PUSH_FRONT_ID_DELTA + 1;
+    const POP_FRONT_ID_DELTA: super::IdDelta = PUSH_FRONT_ID_DELTA_END;
+    const POP_FRONT_ID_DELTA_END: super::IdDelta = POP_FRONT_ID_DELTA + 1;
+    const POP_BACK_ID_DELTA: super::IdDelta = POP_FRONT_ID_DELTA_END;
+    const POP_BACK_ID_DELTA_END: super::IdDelta = POP_BACK_ID_DELTA + 1;
+    const ITEM_ID_DELTA: super::IdDelta = POP_BACK_ID_DELTA_END;
+
+    #[derive(Debug, Clone)]
+    pub enum Message<I: System> {
+        PushBack(I),
+        PushFront(I),
+        PopFront,
+        PopBack,
+        Item(AltIndex, <I as super::State>::Message),
+        State(Deque<I>),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter<I: System> {
+        callback: super::Callback<Deque<I>>,
+        pub push_back: super::Callback<I>,
+        pub push_front: super::Callback<I>,
+        pub pop_front: super::Callback<()>,
+        pub pop_back: super::Callback<()>,
+        pub item: <I as super::State>::Emitter,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser<I: System> {
+        lookup: super::Lookup<Deque<I>>,
+        lookup_len: super::Lookup<usize>,
+        lookup_front: super::Lookup<AltIndex>,
+        pub item: <I as super::State>::Accesser,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n, I: System> {
+        accesser: &'n Accesser<I>,
+        emitter: &'n Emitter<I>,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+        pub item: <I as super::State>::Node<'n>,
+    }
+
+    impl<I: System> super::State for Deque<I> {
+        const NODE_SIZE: super::IdSize = ITEM_ID_DELTA + <I as super::State>::NODE_SIZE;
+        const NODE_ALT_SIZE: super::AltSize = 1;
+
+        type Message = deque::Message<I>;
+        type Emitter = deque::Emitter<I>;
+        type Accesser = deque::Accesser<I>;
+        type Node<'n> = deque::Node<'n, I>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            deque::Message::State(self)
+        }
+    }
+
+    impl<I: System> super::Fallback for Deque<I> {
+        fn fallback(
+            node: Node<'_, I>,
+            message: Message<I>,
+            delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::PushBack(_) => (),
+                Message::PushFront(_) => (),
+                Message::PopFront => (),
+                Message::PopBack => (),
+                Message::Item(index, message) => {
+                    I::handle(
+                        node.item(index).node(),
+                        message,
+                        delta,
+                    )
+                },
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl<I: System> super::System for Deque<I> {
+
+    }
+
+    impl<I: System> super::Message for Message<I> {
+        type State = Deque<I>;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    PUSH_BACK_ID_DELTA..PUSH_BACK_ID_DELTA_END => Ok(Message::PushBack(
+                        packet.decode(depth)?
+                    )),
+                    PUSH_FRONT_ID_DELTA..PUSH_FRONT_ID_DELTA_END => Ok(Message::PushFront(
+                        packet.decode(depth)?
+                    )),
+                    POP_FRONT_ID_DELTA..POP_FRONT_ID_DELTA_END => Ok(Message::PopFront),
+                    POP_BACK_ID_DELTA..POP_BACK_ID_DELTA_END => Ok(Message::PopBack),
+                    ITEM_ID_DELTA.. => {
+                        Ok(Message::Item(
+                            packet.key().transient().index(parent_key.consist().alt_depth()),
+                            <I as super::State>::Message::from_packet(
+                                packet,
+                                super::Key::new(
+                                    parent_key
+                                        .consist()
+                                        .access(ITEM_ID_DELTA, <Deque<I>>::NODE_ALT_SIZE),
+                                    parent_key.transient(),
+                                ),
+                                depth + 1,
+                            )?
+                        ))
+                    }
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::PushBack(item) => super::Packet::new(key, super::State::to_payload(item)),
+                Self::PushFront(item) => super::Packet::new(key, super::State::to_payload(item)),
+                Self::PopFront => super::Packet::new(key, super::State::to_payload(&())),
+                Self::PopBack => super::Packet::new(key, super::State::to_payload(&())),
+                Self::Item(index, message) => message.to_packet(key.alt(*index)),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Deque<I>) {
+            match self {
+                Self::PushBack(item) => state.push_back(item.clone()),
+                Self::PushFront(item) => state.push_front(item.clone()),
+                Self::PopFront => { state.pop_front(); },
+                Self::PopBack => { state.pop_back(); },
+                Self::Item(index, message) => {
+                    if let Some(item) = state.get_mut(*index) {
+                        message.apply_to(item);
+                    }
+                },
+                Self::State(new_state) => *state = new_state.clone(),
+            }
+        }
+    }
+
+    impl<I: System> super::Emitter<Deque<I>> for Emitter<I> {
+        fn callback(&self) -> &super::Callback<Deque<I>> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Deque<I>>) -> Self {
+            Self {
+                push_back: super::Callback::<I>::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    PUSH_BACK_ID_DELTA,
+                    |_, message| {
+                        let mut item = I::default();
+                        super::Message::apply_to(&message, &mut item);
+                        Message::PushBack(item)
+                    },
+                ),
+                push_front: super::Callback::<I>::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    PUSH_FRONT_ID_DELTA,
+                    |_, message| {
+                        let mut item = I::default();
+                        super::Message::apply_to(&message, &mut item);
+                        Message::PushFront(item)
+                    },
+                ),
+                pop_front: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    POP_FRONT_ID_DELTA,
+                    |_, _| Message::PopFront,
+                ),
+                pop_back: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    POP_BACK_ID_DELTA,
+                    |_, _| Message::PopBack,
+                ),
+                item: super::Emitter::new(super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    ITEM_ID_DELTA,
+                    |index, message| Message::Item(index, message),
+                )),
+                callback,
+            }
+        }
+    }
+
+    impl<I: System> super::Accesser<Deque<I>> for Accesser<I> {
+        fn lookup(&self) -> &Lookup<Deque<I>> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: LookupBuilder<CS, Deque<I>>) -> Self {
+            Self {
+                item: super::Accesser::new(builder.access(
+                    |state, index| state.and_then(|state| state.get(index)),
+                    ITEM_ID_DELTA
+                )),
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_len: builder.clone().build(|state| state.map(|state| state.len())),
+                lookup_front: builder.build(|state| state.map(|state| state.front_index())),
+            }
+        }
+    }
+
+    impl<'n, I: System> super::Node<'n, Deque<I>> for Node<'n, I> {
+        fn accesser(&self) -> &Accesser<I> { self.accesser }
+        fn emitter(&self) -> &Emitter<I> { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n, I: System> super::NewNode<'n, Deque<I>> for Node<'n, I> {
+        fn new(
+            accesser: &'n Accesser<I>,
+            emitter: &'n Emitter<I>,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+                item: super::NewNode::new(
+                    &accesser.item,
+                    &emitter.item,
+                    callback_mode,
+                    transient,
+                ),
+            }
+        }
+    }
+
+    impl<'n, I: System> Node<'n, I> {
+        pub fn emit_push_back(&self, item: I) {
+            EmitError::report(self.try_emit_push_back(item));
+        }
+
+        pub fn try_emit_push_back(&self, item: I) -> Result<(), EmitError> {
+            self.emitter.push_back.try_emit(self.callback_mode, self.transient, item.into_message())
+        }
+
+        pub fn emit_push_front(&self, item: I) {
+            EmitError::report(self.try_emit_push_front(item));
+        }
+
+        pub fn try_emit_push_front(&self, item: I) -> Result<(), EmitError> {
+            self.emitter.push_front.try_emit(self.callback_mode, self.transient, item.into_message())
+        }
+
+        pub fn emit_pop_front(&self) {
+            EmitError::report(self.try_emit_pop_front());
+        }
+
+        pub fn try_emit_pop_front(&self) -> Result<(), EmitError> {
+            self.emitter.pop_front.try_emit(self.callback_mode, self.transient, ())
+        }
+
+        pub fn emit_pop_back(&self) {
+            EmitError::report(self.try_emit_pop_back());
+        }
+
+        pub fn try_emit_pop_back(&self) -> Result<(), EmitError> {
+            self.emitter.pop_back.try_emit(self.callback_mode, self.transient, ())
+        }
+
+        pub fn items(&self) -> IntoIter<NodeAlt<'_, I>> {
+            let front = self.front_index();
+            let mut result = Vec::new();
+
+            for position in 0..self.len() {
+                result.push(self.item(front.wrapping_add(position as AltIndex)))
+            }
+
+            result.into_iter()
+        }
+
+        pub fn len(&self) -> usize {
+            self.accesser.lookup_len.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        pub fn front_index(&self) -> AltIndex {
+            self.accesser.lookup_front.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn item(&self, index: AltIndex) -> NodeAlt<'_, I> {
+            use crate::ext::Node;
+            self.item.alt(self.consist(), index)
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use std::{collections::BTreeMap, vec::IntoIter};
+use serde::{Deserialize, Serialize};
+use crate::ext::*;
+
+pub type GridIndex = u16;
+
+// GridIndex 로 가리킬 수 있는 최대 행과 열의 수
+pub const MAX_GRID_LEN: u32 = GridIndex::MAX as u32 + 1;
+
+// (row, col) 을 하나의 AltIndex 로 합쳐 한 단계의 alt 만 사용
+pub fn cell_index(row: GridIndex, col: GridIndex) -> AltIndex {
+    ((row as AltIndex) << GridIndex::BITS) | col as AltIndex
+}
+
+pub fn cell_position(index: AltIndex) -> (GridIndex, GridIndex) {
+    ((index >> GridIndex::BITS) as GridIndex, index as GridIndex)
+}
+
+// 기본값이 아닌 cell 만 보관하는 희소 2차원 격자
+// rows 와 cols 는 MAX_GRID_LEN 으로 제한됨
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Grid<T> {
+    rows: u32,
+    cols: u32,
+    cells: BTreeMap<(GridIndex, GridIndex), T>,
+}
+
+impl<T> Grid<T> {
+    pub fn rows(&self) -> u32 { self.rows }
+    pub fn cols(&self) -> u32 { self.cols }
+    pub fn cells(&self) -> &BTreeMap<(GridIndex, GridIndex), T> { &self.cells }
+
+    pub fn new(rows: u32, cols: u32) -> Self {
+        Self {
+            rows: rows.min(MAX_GRID_LEN),
+            cols: cols.min(MAX_GRID_LEN),
+            cells: BTreeMap::new(),
+        }
+    }
+
+    pub fn contains(&self, row: GridIndex, col: GridIndex) -> bool {
+        (row as u32) < self.rows && (col as u32) < self.cols
+    }
+
+    pub fn get(&self, row: GridIndex, col: GridIndex) -> Option<&T> {
+        self.cells.get(&(row, col))
+    }
+
+    pub fn resize_rows(&mut self, rows: u32) {
+        let rows = rows.min(MAX_GRID_LEN);
+        self.rows = rows;
+        self.cells.retain(|(row, _), _| (*row as u32) < rows);
+    }
+
+    pub fn resize_cols(&mut self, cols: u32) {
+        let cols = cols.min(MAX_GRID_LEN);
+        self.cols = cols;
+        self.cells.retain(|(_, col), _| (*col as u32) < cols);
+    }
+
+    pub fn update(&mut self, row: GridIndex, col: GridIndex, update: impl FnOnce(&mut T))
+    where T: Default + PartialEq {
+        if !self.contains(row, col) {
+            return;
+        }
+
+        let cell = self.cells.entry((row, col)).or_default();
+
+        update(cell);
+
+        if *cell == T::default() {
+            self.cells.remove(&(row, col));
+        }
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod grid {
+    use super::*;
+
+    const RESIZE_ROWS_ID_DELTA: super::IdDelta = 1;
+    const RESIZE_ROWS_ID_DELTA_END: super::IdDelta = RESIZE_ROWS_ID_DELTA + 1;
+    const RESIZE_COLS_ID_DELTA: super::IdDelta = RESIZE_ROWS_ID_DELTA_END;
+    const RESIZE_COLS_ID_DELTA_END: super::IdDelta = RESIZE_COLS_ID_DELTA + 1;
+    const CELL_ID_DELTA: super::IdDelta = RESIZE_COLS_ID_DELTA_END;
+
+    #[derive(Debug, Clone)]
+    pub enum Message<T: System + PartialEq> {
+        ResizeRows(u32),
+        ResizeCols(u32),
+        Cell(GridIndex, GridIndex, <T as super::State>::Message),
+        State(Grid<T>),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter<T: System + PartialEq> {
+        callback: super::Callback<Grid<T>>,
+        pub resize_rows: super::Callback<u32>,
+        pub resize_cols: super::Callback<u32>,
+        pub cell: <T as super::State>::Emitter,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser<T: System + PartialEq> {
+        lookup: super::Lookup<Grid<T>>,
+        lookup_rows: super::Lookup<u32>,
+        lookup_cols: super::Lookup<u32>,
+        lookup_positions: super::Lookup<Vec<(GridIndex, GridIndex)>>,
+        pub cell: <T as super::State>::Accesser,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n, T: System + PartialEq> {
+        accesser: &'n Accesser<T>,
+        emitter: &'n Emitter<T>,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+        pub cell: <T as super::State>::Node<'n>,
+    }
+
+    impl<T: System + PartialEq> super::State for Grid<T> {
+        const NODE_SIZE: super::IdSize = CELL_ID_DELTA + <T as super::State>::NODE_SIZE;
+        const NODE_ALT_SIZE: super::AltSize = 1;
+
+        type Message = grid::Message<T>;
+        type Emitter = grid::Emitter<T>;
+        type Accesser = grid::Accesser<T>;
+        type Node<'n> = grid::Node<'n, T>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            grid::Message::State(self)
+        }
+    }
+
+    impl<T: System + PartialEq> super::Fallback for Grid<T> {
+        fn fallback(
+            node: Node<'_, T>,
+            message: Message<T>,
+            delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::ResizeRows(_) => (),
+                Message::ResizeCols(_) => (),
+                Message::Cell(row, col, message) => {
+                    T::handle(
+                        node.cell(row, col).node(),
+                        message,
+                        delta,
+                    )
+                },
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl<T: System + PartialEq> super::System for Grid<T> {
+
+    }
+
+    impl<T: System + PartialEq> super::Message for Message<T> {
+        type State = Grid<T>;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    RESIZE_ROWS_ID_DELTA..RESIZE_ROWS_ID_DELTA_END => Ok(Message::ResizeRows(
+                        packet.decode(depth)?
+                    )),
+                    RESIZE_COLS_ID_DELTA..RESIZE_COLS_ID_DELTA_END => Ok(Message::ResizeCols(
+                        packet.decode(depth)?
+                    )),
+                    CELL_ID_DELTA.. => {
+                        let (row, col) = cell_position(
+                            packet.key().transient().index(parent_key.consist().alt_depth())
+                        );
+
+                        Ok(Message::Cell(
+                            row,
+                            col,
+                            <T as super::State>::Message::from_packet(
+                                packet,
+                                super::Key::new(
+                                    parent_key
+                                        .consist()
+                                        .access(CELL_ID_DELTA, <Grid<T>>::NODE_ALT_SIZE),
+                                    parent_key.transient(),
+                                ),
+                                depth + 1,
+                            )?
+                        ))
+                    }
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::ResizeRows(rows) => super::Packet::new(key, super::State::to_payload(rows)),
+                Self::ResizeCols(cols) => super::Packet::new(key, super::State::to_payload(cols)),
+                Self::Cell(row, col, message) => message.to_packet(key.alt(cell_index(*row, *col))),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Grid<T>) {
+            match self {
+                Self::ResizeRows(rows) => state.resize_rows(*rows),
+                Self::ResizeCols(cols) => state.resize_cols(*cols),
+                Self::Cell(row, col, message) => {
+                    state.update(*row, *col, |cell| message.apply_to(cell));
+                },
+                Self::State(new_state) => *state = new_state.clone(),
+            }
+        }
+    }
+
+    impl<T: System + PartialEq> super::Emitter<Grid<T>> for Emitter<T> {
+        fn callback(&self) -> &super::Callback<Grid<T>> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Grid<T>>) -> Self {
+            Self {
+                resize_rows: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    RESIZE_ROWS_ID_DELTA,
+                    |_, rows| Message::ResizeRows(rows),
+                ),
+                resize_cols: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    RESIZE_COLS_ID_DELTA,
+                    |_, cols| Message::ResizeCols(cols),
+                ),
+                cell: super::Emitter::new(super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    CELL_ID_DELTA,
+                    |index, message| {
+                        let (row, col) = cell_position(index);
+                        Message::Cell(row, col, message)
+                    },
+                )),
+                callback,
+            }
+        }
+    }
+
+    impl<T: System + PartialEq> super::Accesser<Grid<T>> for Accesser<T> {
+        fn lookup(&self) -> &Lookup<Grid<T>> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: LookupBuilder<CS, Grid<T>>) -> Self {
+            Self {
+                cell: super::Accesser::new(builder.access(
+                    |state, index| {
+                        let (row, col) = cell_position(index);
+                        state.and_then(|state| state.get(row, col))
+                    },
+                    CELL_ID_DELTA
+                )),
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_rows: builder.clone().build(|state| state.map(|state| state.rows())),
+                lookup_cols: builder.clone().build(|state| state.map(|state| state.cols())),
+                lookup_positions: builder.build(|state| state.map(|state| state.cells().keys().copied().collect())),
+            }
+        }
+    }
+
+    impl<'n, T: System + PartialEq> super::Node<'n, Grid<T>> for Node<'n, T> {
+        fn accesser(&self) -> &Accesser<T> { self.accesser }
+        fn emitter(&self) -> &Emitter<T> { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n, T: System + PartialEq> super::NewNode<'n, Grid<T>> for Node<'n, T> {
+        fn new(
+            accesser: &'n Accesser<T>,
+            emitter: &'n Emitter<T>,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+                cell: super::NewNode::new(
+                    &accesser.cell,
+                    &emitter.cell,
+                    callback_mode,
+                    transient,
+                ),
+            }
+        }
+    }
+
+    impl<'n, T: System + PartialEq> Node<'n, T> {
+        pub fn emit_resize_rows(&self, rows: u32) {
+            EmitError::report(self.try_emit_resize_rows(rows));
+        }
+
+        pub fn try_emit_resize_rows(&self, rows: u32) -> Result<(), EmitError> {
+            self.emitter.resize_rows.try_emit(self.callback_mode, self.transient, rows)
+        }
+
+        pub fn emit_resize_cols(&self, cols: u32) {
+            EmitError::report(self.try_emit_resize_cols(cols));
+        }
+
+        pub fn try_emit_resize_cols(&self, cols: u32) -> Result<(), EmitError> {
+            self.emitter.resize_cols.try_emit(self.callback_mode, self.transient, cols)
+        }
+
+        pub fn rows(&self) -> u32 {
+            self.accesser.lookup_rows.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn cols(&self) -> u32 {
+            self.accesser.lookup_cols.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn cells(&self) -> IntoIter<(GridIndex, GridIndex, NodeAlt<'_, T>)> {
+            let positions = self.accesser.lookup_positions.get(self.transient).unwrap_or_default();
+            let mut result = Vec::new();
+
+            for (row, col) in positions {
+                result.push((row, col, self.cell(row, col)))
+            }
+
+            result.into_iter()
+        }
+
+        pub fn cell(&self, row: GridIndex, col: GridIndex) -> NodeAlt<'_, T> {
+            use crate::ext::Node;
+            self.cell.alt(self.consist(), cell_index(row, col))
+        }
+    }
+}
@@ -7,10 +7,23 @@
 pub use prelude::*;
 
 pub mod bases;
+pub mod replica;
 
 pub mod terminal;
 pub mod vec;
+pub mod tuple;
+pub mod pointer;
+pub mod deque;
+pub mod set;
+pub mod blob;
+pub mod text;
+pub mod counter;
+pub mod register;
 pub mod proxy;
+pub mod grid;
+pub mod signal;
+pub mod rpc;
+pub mod slab;
 
 pub mod prelude {
     pub use frand_node_macro::*;
@@ -21,7 +34,8 @@ pub mod prelude {
         consensus::Consensus,
         node::Node,
         system::{Fallback, System},
-        component::Component,
+        component::{Component, Overflow},
+        handle::{ComponentHandle, ComponentOutput},
     };
 }
 
@@ -29,18 +43,37 @@ pub mod ext {
     pub use crate::{
         prelude::*,
         bases::{
-            packet::{IdDelta, IdSize, AltIndex, AltSize, Key, Consist, Id, AltDepth, Transient, Payload, Packet, MessagePacket},
+            packet::{IdDelta, IdSize, AltIndex, AltSize, Key, Consist, Id, AltDepth, Transient, Payload, Packet, MessagePacket, CarryTiming, FutureHandle},
             callback::{Callback, CallbackMode},
-            lookup::{Lookup, LookupBuilder},
+            lookup::{Lookup, LookupBuilder, Query},
             emitter::Emitter,
             accesser::Accesser,
             node::{NewNode, NodeAlt},
-            result::{Result, PacketError},
+            result::{Result, PacketError, EmitError, CascadeError, ReplayError},
+            history::{History, Grouping},
+            journal::{Journal, JournalPolicy, Fsync},
+            session::{Session, SessionTick, SessionInput, Recorder, Replayer},
         },
-        terminal::terminal,
+        terminal::{terminal, Timestamp, Quantized},
         vec::vec,
+        tuple::{tuple2, tuple3, tuple4, tuple5, tuple6, tuple7, tuple8},
+        pointer::{boxed, arc},
+        deque::{deque, Deque},
+        set::{btree_set, hash_set},
+        blob::{blob, Blob},
+        text::{text, Text},
+        replica::{ReplicaId, Replica, Clock},
+        counter::{counter, Counter},
+        register::{register, Register},
         proxy::{proxy, Proxy},
+        grid::{grid, Grid},
+        signal::{signal, Signal},
+        rpc::{rpc, Rpc},
+        slab::{slab, Slab},
     };
+
+    #[cfg(feature = "time")]
+    pub use crate::terminal::{OffsetDateTime, PrimitiveDateTime, Date};
 }
 
 mod frand_node {
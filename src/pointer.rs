@@ -0,0 +1,173 @@
+use std::{ops::Deref, sync::Arc};
+use crate::ext::*;
+
+#[allow(clippy::boxed_local)]
+fn unbox<T>(state: Box<T>) -> T { *state }
+
+macro_rules! impl_pointer_node {
+    ( $( $module: ident for $pointer: ident { as_mut: $as_mut: path, into_inner: $into_inner: path $(,)? } )+ ) => {
+        $(
+            pub mod $module {
+                use super::*;
+
+                #[derive(Debug, Clone)]
+                pub struct Message<T: System>(pub <T as super::State>::Message);
+
+                #[derive(Debug, Clone)]
+                pub struct Emitter<T: System> {
+                    callback: super::Callback<$pointer<T>>,
+                    pub inner: <T as super::State>::Emitter,
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Accesser<T: System> {
+                    lookup: super::Lookup<$pointer<T>>,
+                    pub inner: <T as super::State>::Accesser,
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Node<'n, T: System> {
+                    accesser: &'n Accesser<T>,
+                    emitter: &'n Emitter<T>,
+                    callback_mode: &'n CallbackMode,
+                    transient: &'n super::Transient,
+                    inner: <T as super::State>::Node<'n>,
+                }
+
+                impl<T: System> super::State for $pointer<T> {
+                    const NODE_SIZE: super::IdSize = <T as super::State>::NODE_SIZE;
+                    const NODE_ALT_SIZE: super::AltSize = 0;
+
+                    type Message = Message<T>;
+                    type Emitter = Emitter<T>;
+                    type Accesser = Accesser<T>;
+                    type Node<'n> = Node<'n, T>;
+
+                    fn from_payload(payload: &super::Payload) -> Self {
+                        super::Payload::to_state(payload)
+                    }
+
+                    fn to_payload(&self) -> super::Payload {
+                        super::Payload::from_state(self)
+                    }
+
+                    fn into_message(self) -> Self::Message {
+                        Message($into_inner(self).into_message())
+                    }
+                }
+
+                impl<T: System> super::Fallback for $pointer<T> {
+                    fn fallback(
+                        node: Node<'_, T>,
+                        message: Message<T>,
+                        delta: Option<std::time::Duration>,
+                    ) {
+                        T::handle(node.inner, message.0, delta)
+                    }
+                }
+
+                impl<T: System> super::System for $pointer<T> {
+
+                }
+
+                impl<T: System> super::Message for Message<T> {
+                    type State = $pointer<T>;
+
+                    fn from_packet(
+                        packet: &super::Packet,
+                        parent_key: super::Key,
+                        depth: usize,
+                    ) -> super::Result<Self> {
+                        Ok(Message(<T as super::State>::Message::from_packet(
+                            packet,
+                            parent_key,
+                            depth,
+                        )?))
+                    }
+
+                    fn to_packet(&self, key: super::Key) -> super::Packet {
+                        self.0.to_packet(key)
+                    }
+
+                    fn apply_to(&self, state: &mut $pointer<T>) {
+                        self.0.apply_to($as_mut(state))
+                    }
+                }
+
+                impl<T: System> super::Emitter<$pointer<T>> for Emitter<T> {
+                    fn callback(&self) -> &super::Callback<$pointer<T>> {
+                        &self.callback
+                    }
+
+                    fn new(callback: super::Callback<$pointer<T>>) -> Self {
+                        Self {
+                            inner: super::Emitter::new(super::Callback::access(
+                                *callback.consist(),
+                                callback.callback().clone(),
+                                callback.process().clone(),
+                                0,
+                                |_, message| Message(message),
+                            )),
+                            callback,
+                        }
+                    }
+                }
+
+                impl<T: System> super::Accesser<$pointer<T>> for Accesser<T> {
+                    fn lookup(&self) -> &super::Lookup<$pointer<T>> {
+                        &self.lookup
+                    }
+
+                    fn new<CS: System>(builder: super::LookupBuilder<CS, $pointer<T>>) -> Self {
+                        Self {
+                            inner: super::Accesser::new(builder.access(
+                                |state, _| state.map(|state| &**state),
+                                0,
+                            )),
+                            lookup: builder.build(|state| state.cloned()),
+                        }
+                    }
+                }
+
+                impl<'n, T: System> super::Node<'n, $pointer<T>> for Node<'n, T> {
+                    fn accesser(&self) -> &Accesser<T> { self.accesser }
+                    fn emitter(&self) -> &Emitter<T> { self.emitter }
+                    fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+                    fn transient(&self) -> &super::Transient { self.transient }
+                }
+
+                impl<'n, T: System> super::NewNode<'n, $pointer<T>> for Node<'n, T> {
+                    fn new(
+                        accesser: &'n Accesser<T>,
+                        emitter: &'n Emitter<T>,
+                        callback_mode: &'n CallbackMode,
+                        transient: &'n super::Transient,
+                    ) -> Self {
+                        Self {
+                            accesser,
+                            emitter,
+                            callback_mode,
+                            transient,
+                            inner: super::NewNode::new(
+                                &accesser.inner,
+                                &emitter.inner,
+                                callback_mode,
+                                transient,
+                            ),
+                        }
+                    }
+                }
+
+                impl<'n, T: System> Deref for Node<'n, T> {
+                    type Target = <T as super::State>::Node<'n>;
+                    fn deref(&self) -> &Self::Target { &self.inner }
+                }
+            }
+        )+
+    };
+}
+
+impl_pointer_node! {
+    boxed for Box { as_mut: AsMut::as_mut, into_inner: unbox }
+    arc for Arc { as_mut: Arc::make_mut, into_inner: Arc::unwrap_or_clone }
+}
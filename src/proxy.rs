@@ -8,6 +8,7 @@ pub struct Proxy<S: State> {
     _phantom: PhantomData<S>,
 }
 
+#[allow(clippy::module_inception)]
 pub mod proxy {
     use super::*;
 
@@ -73,6 +74,7 @@ pub mod proxy {
     impl<S: State> super::Message for Message<S> {
         type State = Proxy<S>;
 
+        #[allow(clippy::needless_question_mark)]
         fn from_packet(
             packet: &super::Packet,
             parent_key: super::Key,
@@ -80,7 +82,7 @@ pub mod proxy {
         ) -> super::Result<Self> {
             Ok(
                 match packet.key().consist().id() - parent_key.consist().id() {
-                    0 => Ok(Self::State(super::State::from_payload(packet.payload()))),
+                    0 => Ok(Self::State(packet.decode(depth)?)),
                     id_delta => Err(super::PacketError::new(
                         packet.clone(),
                         Some(id_delta),
@@ -0,0 +1,300 @@
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+use crate::{ext::*, replica::{new_replica, wall_clock}};
+
+// Hybrid Logical Clock
+// wall 은 UNIX_EPOCH 로부터의 밀리초이며 같은 wall 안에서는 logical 로, 그마저 같으면 replica 로 순서를 정함
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Hlc {
+    pub wall: u64,
+    pub logical: u32,
+    pub replica: ReplicaId,
+}
+
+impl Hlc {
+    // now 는 UNIX_EPOCH 로부터의 밀리초
+    pub fn tick(self, replica: ReplicaId, now: u64) -> Self {
+        if self.wall < now {
+            Self { wall: now, logical: 0, replica }
+        } else {
+            match self.logical.checked_add(1) {
+                Some(logical) => Self { wall: self.wall, logical, replica },
+                None => Self { wall: self.wall + 1, logical: 0, replica },
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Stamped<T> {
+    pub value: T,
+    pub stamp: Hlc,
+}
+
+// Last-Writer-Wins Register
+// 더 큰 Hlc 를 가진 값이 항상 이기므로 메시지의 적용 순서와 무관하게 같은 값으로 수렴함
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Register<T> {
+    value: T,
+    stamp: Hlc,
+    #[serde(skip)]
+    issued: Mutex<Hlc>,
+}
+
+impl<T: Default> Default for Register<T> {
+    fn default() -> Self {
+        Self {
+            value: T::default(),
+            stamp: Hlc::default(),
+            issued: Mutex::default(),
+        }
+    }
+}
+
+impl<T: Clone> Clone for Register<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            stamp: self.stamp,
+            issued: Mutex::new(*self.issued.lock().unwrap()),
+        }
+    }
+}
+
+// 현재 시각으로 stamp 하여 State 로 emit 했을 때 이전 값을 이기도록 함
+impl<T: Default> From<T> for Register<T> {
+    fn from(value: T) -> Self {
+        Self {
+            value,
+            stamp: Hlc::default().tick(new_replica(), wall_clock()),
+            issued: Mutex::default(),
+        }
+    }
+}
+
+impl<T> Register<T> {
+    pub fn value(&self) -> &T { &self.value }
+    pub fn stamp(&self) -> Hlc { self.stamp }
+    // node 에서 emit 할 때는 Component 의 replica id 와 시계로 stamp
+    pub fn prepare_set(&self, value: T, replica: ReplicaId, now: u64) -> Stamped<T> {
+        let mut issued = self.issued.lock().unwrap();
+        *issued = (*issued).max(self.stamp).tick(replica, now);
+
+        Stamped {
+            value,
+            stamp: *issued,
+        }
+    }
+
+    pub fn apply_set(&mut self, stamped: &Stamped<T>) where T: Clone {
+        if self.stamp < stamped.stamp {
+            self.value = stamped.value.clone();
+            self.stamp = stamped.stamp;
+        }
+    }
+
+    pub fn merge(&mut self, other: &Register<T>) where T: Clone {
+        if self.stamp < other.stamp {
+            self.value = other.value.clone();
+            self.stamp = other.stamp;
+        }
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod register {
+    use super::*;
+
+    const SET_ID_DELTA: super::IdDelta = 1;
+    const SET_ID_DELTA_END: super::IdDelta = SET_ID_DELTA + 1;
+
+    #[derive(Debug, Clone)]
+    pub enum Message<T: State> {
+        Set(Stamped<T>),
+        State(Register<T>),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter<T: State> {
+        callback: super::Callback<Register<T>>,
+        pub set: super::Callback<Register<T>>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser<T: State> {
+        lookup: super::Lookup<Register<T>>,
+        lookup_value: super::Lookup<T>,
+        lookup_stamp: super::Lookup<Hlc>,
+        query_set: super::Query<T, Stamped<T>>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n, T: State> {
+        accesser: &'n Accesser<T>,
+        emitter: &'n Emitter<T>,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+    }
+
+    impl<T: State> super::State for Register<T> {
+        const NODE_SIZE: super::IdSize = SET_ID_DELTA_END;
+        const NODE_ALT_SIZE: super::AltSize = 0;
+
+        type Message = register::Message<T>;
+        type Emitter = register::Emitter<T>;
+        type Accesser = register::Accesser<T>;
+        type Node<'n> = register::Node<'n, T>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            register::Message::State(self)
+        }
+    }
+
+    impl<T: State> super::Fallback for Register<T> {
+        fn fallback(
+            _node: Node<'_, T>,
+            message: Message<T>,
+            _delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::Set(_) => (),
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl<T: State> super::System for Register<T> {
+
+    }
+
+    impl<T: State> super::Message for Message<T> {
+        type State = Register<T>;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    SET_ID_DELTA..SET_ID_DELTA_END => Ok(Message::Set(
+                        packet.decode(depth)?
+                    )),
+                    id_delta => Err(super::PacketError::new(
+                        packet.clone(),
+                        Some(id_delta),
+                        Some(depth),
+                        format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                    )),
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::Set(stamped) => super::Packet::new(key, super::Payload::from_value(stamped)),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Register<T>) {
+            match self {
+                Self::Set(stamped) => state.apply_set(stamped),
+                Self::State(new_state) => state.merge(new_state),
+            }
+        }
+    }
+
+    impl<T: State> super::Emitter<Register<T>> for Emitter<T> {
+        fn callback(&self) -> &super::Callback<Register<T>> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Register<T>>) -> Self {
+            Self {
+                set: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    SET_ID_DELTA,
+                    |_, message| message,
+                ),
+                callback,
+            }
+        }
+    }
+
+    impl<T: State> super::Accesser<Register<T>> for Accesser<T> {
+        fn lookup(&self) -> &super::Lookup<Register<T>> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: super::LookupBuilder<CS, Register<T>>) -> Self {
+            Self {
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_value: builder.clone().build(|state| state.map(|state| state.value().clone())),
+                lookup_stamp: builder.clone().build(|state| state.map(|state| state.stamp())),
+                query_set: builder.build_replica_query(|state, replica, value| {
+                    state.map(|state| state.prepare_set(value.clone(), replica.id(), replica.now()))
+                }),
+            }
+        }
+    }
+
+    impl<'n, T: State> super::Node<'n, Register<T>> for Node<'n, T> {
+        fn accesser(&self) -> &Accesser<T> { self.accesser }
+        fn emitter(&self) -> &Emitter<T> { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n, T: State> super::NewNode<'n, Register<T>> for Node<'n, T> {
+        fn new(
+            accesser: &'n Accesser<T>,
+            emitter: &'n Emitter<T>,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+            }
+        }
+    }
+
+    impl<'n, T: State> Node<'n, T> {
+        pub fn emit_set(&self, value: T) {
+            EmitError::report(self.try_emit_set(value));
+        }
+
+        pub fn try_emit_set(&self, value: T) -> Result<(), EmitError> {
+            if let Some(stamped) = self.accesser.query_set.get(self.transient, &value) {
+                self.emitter.set.try_emit(self.callback_mode, self.transient, Message::Set(stamped))?;
+            }
+
+            Ok(())
+        }
+
+        pub fn value(&self) -> T {
+            self.accesser.lookup_value.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn stamp(&self) -> Hlc {
+            self.accesser.lookup_stamp.get(self.transient).unwrap_or_default()
+        }
+    }
+}
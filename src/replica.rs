@@ -0,0 +1,71 @@
+use std::{collections::hash_map::RandomState, hash::{BuildHasher, Hasher}, sync::Arc, time::SystemTime};
+
+pub type ReplicaId = u64;
+
+// UNIX_EPOCH 로부터의 밀리초를 반환하는 시계
+pub type Clock = Arc<dyn Fn() -> u64 + Send + Sync>;
+
+pub fn new_replica() -> ReplicaId {
+    RandomState::new().build_hasher().finish()
+}
+
+pub fn wall_clock() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+// Component 가 node 에 제공하는 replica id 와 시계
+// update 가 처리되는 동안에는 시각을 고정하여 handler 의 emit 이 Session 에 기록된 시각으로 재현되도록 함
+#[derive(Clone)]
+pub struct Replica {
+    id: ReplicaId,
+    clock: Clock,
+    frozen: Option<u64>,
+}
+
+impl std::fmt::Debug for Replica {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Replica")
+        .field("id", &self.id)
+        .field("frozen", &self.frozen)
+        .finish()
+    }
+}
+
+impl Default for Replica {
+    fn default() -> Self {
+        Self {
+            id: new_replica(),
+            clock: Arc::new(wall_clock),
+            frozen: None,
+        }
+    }
+}
+
+impl Replica {
+    pub fn id(&self) -> ReplicaId { self.id }
+
+    pub fn now(&self) -> u64 {
+        self.frozen.unwrap_or_else(|| (self.clock)())
+    }
+
+    pub(crate) fn set_id(&mut self, id: ReplicaId) {
+        self.id = id;
+    }
+
+    pub(crate) fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    pub(crate) fn freeze(&mut self) -> u64 {
+        let now = (self.clock)();
+        self.frozen = Some(now);
+        now
+    }
+
+    pub(crate) fn thaw(&mut self) {
+        self.frozen = None;
+    }
+}
@@ -0,0 +1,327 @@
+use std::{collections::HashMap, marker::PhantomData, sync::{atomic::{AtomicU32, Ordering}, Mutex}};
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use crate::ext::*;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct RequestId {
+    pub replica: ReplicaId,
+    pub sequence: u32,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Request<T> {
+    pub id: RequestId,
+    pub value: T,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Response<T> {
+    pub id: RequestId,
+    pub value: T,
+}
+
+// 요청과 응답을 packet 으로 주고받고, 요청한 replica 에서 응답이 적용될 때 future 를 완료함
+// 요청 id 는 Component 의 replica id 를 사용하고, 대기 중인 요청은 replica 마다 따로 보관되며 snapshot 이나 복제된 상태에는 포함되지 않음
+// 응답을 더 기다리지 않을 때 Receiver 를 drop 하면 (timeout 등) 대기 중인 요청에서 정리됨
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Rpc<Req, Resp> {
+    #[serde(skip)]
+    issued: AtomicU32,
+    #[serde(skip)]
+    pending: Mutex<HashMap<RequestId, oneshot::Sender<Resp>>>,
+    #[serde(skip)]
+    _marker: PhantomData<fn() -> Req>,
+}
+
+impl<Req, Resp> Default for Rpc<Req, Resp> {
+    fn default() -> Self {
+        Self {
+            issued: AtomicU32::new(0),
+            pending: Mutex::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Req, Resp> Clone for Rpc<Req, Resp> {
+    fn clone(&self) -> Self {
+        Self {
+            issued: AtomicU32::new(self.issued.load(Ordering::Relaxed)),
+            pending: Mutex::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Req, Resp> Rpc<Req, Resp> {
+    pub fn pending_len(&self) -> usize {
+        let mut pending = self.pending.lock().unwrap();
+        pending.retain(|_, tx| !tx.is_closed());
+        pending.len()
+    }
+
+    pub fn prepare_request(&self, replica: ReplicaId) -> (RequestId, oneshot::Receiver<Resp>) {
+        let id = RequestId {
+            replica,
+            sequence: self.issued.fetch_add(1, Ordering::Relaxed),
+        };
+
+        let (tx, rx) = oneshot::channel();
+
+        let mut pending = self.pending.lock().unwrap();
+        pending.retain(|_, tx| !tx.is_closed());
+        pending.insert(id, tx);
+
+        (id, rx)
+    }
+
+    // 다른 replica 의 요청에 대한 응답이나 이미 완료된 요청에 대한 응답은 무시됨
+    pub fn apply_response(&self, response: &Response<Resp>) where Resp: Clone {
+        if let Some(tx) = self.pending.lock().unwrap().remove(&response.id) {
+            tx.send(response.value.clone()).ok();
+        }
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod rpc {
+    use super::*;
+
+    const REQUEST_ID_DELTA: super::IdDelta = 1;
+    const REQUEST_ID_DELTA_END: super::IdDelta = REQUEST_ID_DELTA + 1;
+    const RESPONSE_ID_DELTA: super::IdDelta = REQUEST_ID_DELTA_END;
+    const RESPONSE_ID_DELTA_END: super::IdDelta = RESPONSE_ID_DELTA + 1;
+
+    #[derive(Debug, Clone)]
+    pub enum Message<Req: State, Resp: State> {
+        Request(Request<Req>),
+        Response(Response<Resp>),
+        State(Rpc<Req, Resp>),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter<Req: State, Resp: State> {
+        callback: super::Callback<Rpc<Req, Resp>>,
+        pub request: super::Callback<Rpc<Req, Resp>>,
+        pub response: super::Callback<Rpc<Req, Resp>>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser<Req: State, Resp: State> {
+        lookup: super::Lookup<Rpc<Req, Resp>>,
+        lookup_pending_len: super::Lookup<usize>,
+        query_request: super::Query<(), (RequestId, oneshot::Receiver<Resp>)>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n, Req: State, Resp: State> {
+        accesser: &'n Accesser<Req, Resp>,
+        emitter: &'n Emitter<Req, Resp>,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+    }
+
+    impl<Req: State, Resp: State> super::State for Rpc<Req, Resp> {
+        const NODE_SIZE: super::IdSize = RESPONSE_ID_DELTA_END;
+        const NODE_ALT_SIZE: super::AltSize = 1;
+
+        type Message = rpc::Message<Req, Resp>;
+        type Emitter = rpc::Emitter<Req, Resp>;
+        type Accesser = rpc::Accesser<Req, Resp>;
+        type Node<'n> = rpc::Node<'n, Req, Resp>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            rpc::Message::State(self)
+        }
+    }
+
+    impl<Req: State, Resp: State> super::Fallback for Rpc<Req, Resp> {
+        fn fallback(
+            _node: Node<'_, Req, Resp>,
+            message: Message<Req, Resp>,
+            _delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::Request(_) => (),
+                Message::Response(_) => (),
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl<Req: State, Resp: State> super::System for Rpc<Req, Resp> {
+
+    }
+
+    impl<Req: State, Resp: State> super::Message for Message<Req, Resp> {
+        type State = Rpc<Req, Resp>;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    REQUEST_ID_DELTA..REQUEST_ID_DELTA_END => Ok(Message::Request(
+                        packet.decode(depth)?
+                    )),
+                    RESPONSE_ID_DELTA..RESPONSE_ID_DELTA_END => Ok(Message::Response(
+                        packet.decode(depth)?
+                    )),
+                    id_delta => Err(super::PacketError::new(
+                        packet.clone(),
+                        Some(id_delta),
+                        Some(depth),
+                        format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                    )),
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::Request(request) => super::Packet::new(key, super::Payload::from_value(request)),
+                Self::Response(response) => super::Packet::new(key, super::Payload::from_value(response)),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Rpc<Req, Resp>) {
+            match self {
+                Self::Request(_) => (),
+                Self::Response(response) => state.apply_response(response),
+                Self::State(_) => (),
+            }
+        }
+    }
+
+    impl<Req: State, Resp: State> super::Emitter<Rpc<Req, Resp>> for Emitter<Req, Resp> {
+        fn callback(&self) -> &super::Callback<Rpc<Req, Resp>> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Rpc<Req, Resp>>) -> Self {
+            Self {
+                request: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    REQUEST_ID_DELTA,
+                    |_, message| message,
+                ),
+                response: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    RESPONSE_ID_DELTA,
+                    |_, message| message,
+                ),
+                callback,
+            }
+        }
+    }
+
+    impl<Req: State, Resp: State> super::Accesser<Rpc<Req, Resp>> for Accesser<Req, Resp> {
+        fn lookup(&self) -> &super::Lookup<Rpc<Req, Resp>> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: super::LookupBuilder<CS, Rpc<Req, Resp>>) -> Self {
+            Self {
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_pending_len: builder.clone().build(|state| state.map(|state| state.pending_len())),
+                query_request: builder.build_replica_query(|state, replica, _| {
+                    state.map(|state| state.prepare_request(replica.id()))
+                }),
+            }
+        }
+    }
+
+    impl<'n, Req: State, Resp: State> super::Node<'n, Rpc<Req, Resp>> for Node<'n, Req, Resp> {
+        fn accesser(&self) -> &Accesser<Req, Resp> { self.accesser }
+        fn emitter(&self) -> &Emitter<Req, Resp> { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n, Req: State, Resp: State> super::NewNode<'n, Rpc<Req, Resp>> for Node<'n, Req, Resp> {
+        fn new(
+            accesser: &'n Accesser<Req, Resp>,
+            emitter: &'n Emitter<Req, Resp>,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+            }
+        }
+    }
+
+    impl<'n, Req: State, Resp: State> Node<'n, Req, Resp> {
+        // 반환된 Receiver 는 이 replica 에 응답이 적용될 때 완료되며
+        // 상태에 접근할 수 없으면 즉시 RecvError 로 완료됨
+        pub fn emit_request(&self, value: Req) -> oneshot::Receiver<Resp> {
+            self.try_emit_request(value).unwrap_or_else(|err| {
+                EmitError::report(Err(err));
+                oneshot::channel().1
+            })
+        }
+
+        // emit 에 실패하면 Receiver 가 drop 되어 대기 중인 요청에서 정리됨
+        pub fn try_emit_request(&self, value: Req) -> Result<oneshot::Receiver<Resp>, EmitError> {
+            match self.accesser.query_request.get(self.transient, &()) {
+                Some((id, rx)) => {
+                    self.emitter.request.try_emit(
+                        self.callback_mode,
+                        &self.transient_of(&id),
+                        Message::Request(Request { id, value }),
+                    )?;
+
+                    Ok(rx)
+                },
+                None => Ok(oneshot::channel().1),
+            }
+        }
+
+        pub fn emit_response(&self, id: RequestId, value: Resp) {
+            EmitError::report(self.try_emit_response(id, value));
+        }
+
+        pub fn try_emit_response(&self, id: RequestId, value: Resp) -> Result<(), EmitError> {
+            self.emitter.response.try_emit(
+                self.callback_mode,
+                &self.transient_of(&id),
+                Message::Response(Response { id, value }),
+            )
+        }
+
+        pub fn pending_len(&self) -> usize {
+            self.accesser.lookup_pending_len.get(self.transient).unwrap_or_default()
+        }
+
+        // 같은 Tick 의 여러 요청과 응답이 중복 제거되지 않도록 replica 와 sequence 로 alt index 를 만듦
+        // 같은 replica 의 요청끼리는 겹치지 않음
+        fn transient_of(&self, id: &RequestId) -> super::Transient {
+            use crate::ext::Node;
+            let replica = (id.replica ^ (id.replica >> 32)) as AltIndex;
+            self.transient.alt(self.consist().alt_depth(), id.sequence.wrapping_add(replica))
+        }
+    }
+}
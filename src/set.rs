@@ -0,0 +1,262 @@
+use std::{collections::{BTreeSet, HashSet}, hash::Hash};
+use crate::ext::*;
+
+macro_rules! impl_set_node {
+    ( $( $module: ident for $set: ident { $($bound: tt)+ } )+ ) => {
+        $(
+            pub mod $module {
+                use super::*;
+
+                const INSERT_ID_DELTA: super::IdDelta = 1;
+                const INSERT_ID_DELTA_END: super::IdDelta = INSERT_ID_DELTA + 1;
+                const REMOVE_ID_DELTA: super::IdDelta = INSERT_ID_DELTA_END;
+                const REMOVE_ID_DELTA_END: super::IdDelta = REMOVE_ID_DELTA + 1;
+                const CLEAR_ID_DELTA: super::IdDelta = REMOVE_ID_DELTA_END;
+                const CLEAR_ID_DELTA_END: super::IdDelta = CLEAR_ID_DELTA + 1;
+
+                #[derive(Debug, Clone)]
+                pub enum Message<T: State + $($bound)+> {
+                    Insert(T),
+                    Remove(T),
+                    Clear,
+                    State($set<T>),
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Emitter<T: State + $($bound)+> {
+                    callback: super::Callback<$set<T>>,
+                    pub insert: super::Callback<T>,
+                    pub remove: super::Callback<T>,
+                    pub clear: super::Callback<()>,
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Accesser<T: State + $($bound)+> {
+                    lookup: super::Lookup<$set<T>>,
+                    lookup_len: super::Lookup<usize>,
+                    query_contains: super::Query<T, bool>,
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Node<'n, T: State + $($bound)+> {
+                    accesser: &'n Accesser<T>,
+                    emitter: &'n Emitter<T>,
+                    callback_mode: &'n CallbackMode,
+                    transient: &'n super::Transient,
+                }
+
+                impl<T: State + $($bound)+> super::State for $set<T> {
+                    const NODE_SIZE: super::IdSize = CLEAR_ID_DELTA_END;
+                    const NODE_ALT_SIZE: super::AltSize = 0;
+
+                    type Message = Message<T>;
+                    type Emitter = Emitter<T>;
+                    type Accesser = Accesser<T>;
+                    type Node<'n> = Node<'n, T>;
+
+                    fn from_payload(payload: &super::Payload) -> Self {
+                        super::Payload::to_state(payload)
+                    }
+
+                    fn to_payload(&self) -> super::Payload {
+                        super::Payload::from_state(self)
+                    }
+
+                    fn into_message(self) -> Self::Message {
+                        Message::State(self)
+                    }
+                }
+
+                impl<T: State + $($bound)+> super::Fallback for $set<T> {
+                    fn fallback(
+                        _node: Node<'_, T>,
+                        message: Message<T>,
+                        _delta: Option<std::time::Duration>,
+                    ) {
+                        match message {
+                            Message::Insert(_) => (),
+                            Message::Remove(_) => (),
+                            Message::Clear => (),
+                            Message::State(_) => (),
+                        }
+                    }
+                }
+
+                impl<T: State + $($bound)+> super::System for $set<T> {
+
+                }
+
+                impl<T: State + $($bound)+> super::Message for Message<T> {
+                    type State = $set<T>;
+
+                    #[allow(clippy::needless_question_mark)]
+                    fn from_packet(
+                        packet: &super::Packet,
+                        parent_key: super::Key,
+                        depth: usize,
+                    ) -> super::Result<Self> {
+                        Ok(
+                            match packet.key().consist().id() - parent_key.consist().id() {
+                                0 => Ok(Self::State(
+                                    packet.decode(depth)?
+                                )),
+                                INSERT_ID_DELTA..INSERT_ID_DELTA_END => Ok(Message::Insert(
+                                    packet.decode(depth)?
+                                )),
+                                REMOVE_ID_DELTA..REMOVE_ID_DELTA_END => Ok(Message::Remove(
+                                    packet.decode(depth)?
+                                )),
+                                CLEAR_ID_DELTA..CLEAR_ID_DELTA_END => Ok(Message::Clear),
+                                id_delta => Err(super::PacketError::new(
+                                    packet.clone(),
+                                    Some(id_delta),
+                                    Some(depth),
+                                    format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                                )),
+                            }?,
+                        )
+                    }
+
+                    fn to_packet(&self, key: super::Key) -> super::Packet {
+                        match self {
+                            Self::Insert(item) => super::Packet::new(key, super::State::to_payload(item)),
+                            Self::Remove(item) => super::Packet::new(key, super::State::to_payload(item)),
+                            Self::Clear => super::Packet::new(key, super::State::to_payload(&())),
+                            Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+                        }
+                    }
+
+                    fn apply_to(&self, state: &mut $set<T>) {
+                        match self {
+                            Self::Insert(item) => { state.insert(item.clone()); },
+                            Self::Remove(item) => { state.remove(item); },
+                            Self::Clear => state.clear(),
+                            Self::State(new_state) => *state = new_state.clone(),
+                        }
+                    }
+                }
+
+                impl<T: State + $($bound)+> super::Emitter<$set<T>> for Emitter<T> {
+                    fn callback(&self) -> &super::Callback<$set<T>> {
+                        &self.callback
+                    }
+
+                    fn new(callback: super::Callback<$set<T>>) -> Self {
+                        Self {
+                            insert: super::Callback::<T>::access(
+                                *callback.consist(),
+                                callback.callback().clone(),
+                                callback.process().clone(),
+                                INSERT_ID_DELTA,
+                                |_, message| {
+                                    let mut item = T::default();
+                                    super::Message::apply_to(&message, &mut item);
+                                    Message::Insert(item)
+                                },
+                            ),
+                            remove: super::Callback::<T>::access(
+                                *callback.consist(),
+                                callback.callback().clone(),
+                                callback.process().clone(),
+                                REMOVE_ID_DELTA,
+                                |_, message| {
+                                    let mut item = T::default();
+                                    super::Message::apply_to(&message, &mut item);
+                                    Message::Remove(item)
+                                },
+                            ),
+                            clear: super::Callback::access(
+                                *callback.consist(),
+                                callback.callback().clone(),
+                                callback.process().clone(),
+                                CLEAR_ID_DELTA,
+                                |_, _| Message::Clear,
+                            ),
+                            callback,
+                        }
+                    }
+                }
+
+                impl<T: State + $($bound)+> super::Accesser<$set<T>> for Accesser<T> {
+                    fn lookup(&self) -> &super::Lookup<$set<T>> {
+                        &self.lookup
+                    }
+
+                    fn new<CS: System>(builder: super::LookupBuilder<CS, $set<T>>) -> Self {
+                        Self {
+                            lookup: builder.clone().build(|state| state.cloned()),
+                            lookup_len: builder.clone().build(|state| state.map(|state| state.len())),
+                            query_contains: builder.build_query(|state, item| state.map(|state| state.contains(item))),
+                        }
+                    }
+                }
+
+                impl<'n, T: State + $($bound)+> super::Node<'n, $set<T>> for Node<'n, T> {
+                    fn accesser(&self) -> &Accesser<T> { self.accesser }
+                    fn emitter(&self) -> &Emitter<T> { self.emitter }
+                    fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+                    fn transient(&self) -> &super::Transient { self.transient }
+                }
+
+                impl<'n, T: State + $($bound)+> super::NewNode<'n, $set<T>> for Node<'n, T> {
+                    fn new(
+                        accesser: &'n Accesser<T>,
+                        emitter: &'n Emitter<T>,
+                        callback_mode: &'n CallbackMode,
+                        transient: &'n super::Transient,
+                    ) -> Self {
+                        Self {
+                            accesser,
+                            emitter,
+                            callback_mode,
+                            transient,
+                        }
+                    }
+                }
+
+                impl<'n, T: State + $($bound)+> Node<'n, T> {
+                    pub fn emit_insert(&self, item: T) {
+                        EmitError::report(self.try_emit_insert(item));
+                    }
+
+                    pub fn try_emit_insert(&self, item: T) -> Result<(), EmitError> {
+                        self.emitter.insert.try_emit(self.callback_mode, self.transient, item.into_message())
+                    }
+
+                    pub fn emit_remove(&self, item: T) {
+                        EmitError::report(self.try_emit_remove(item));
+                    }
+
+                    pub fn try_emit_remove(&self, item: T) -> Result<(), EmitError> {
+                        self.emitter.remove.try_emit(self.callback_mode, self.transient, item.into_message())
+                    }
+
+                    pub fn emit_clear(&self) {
+                        EmitError::report(self.try_emit_clear());
+                    }
+
+                    pub fn try_emit_clear(&self) -> Result<(), EmitError> {
+                        self.emitter.clear.try_emit(self.callback_mode, self.transient, ())
+                    }
+
+                    pub fn contains(&self, item: &T) -> bool {
+                        self.accesser.query_contains.get(self.transient, item).unwrap_or_default()
+                    }
+
+                    pub fn len(&self) -> usize {
+                        self.accesser.lookup_len.get(self.transient).unwrap_or_default()
+                    }
+
+                    pub fn is_empty(&self) -> bool {
+                        self.len() == 0
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_set_node! {
+    btree_set for BTreeSet { Ord }
+    hash_set for HashSet { Eq + Hash }
+}
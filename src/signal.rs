@@ -0,0 +1,232 @@
+use std::{fmt::Debug, marker::PhantomData, sync::{atomic::{AtomicU32, Ordering}, Arc}};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::ext::*;
+
+// 값을 상태에 보관하지 않고 handler 에 전달만 하는 이벤트 전용 노드
+// snapshot 에는 항상 unit 으로 직렬화됨
+// emit_each 의 순번을 담기 위해 alt depth 를 하나 사용하므로 Vec, Grid, Slab 처럼 alt 를 사용하는 노드 아래에서는
+// 모두 합쳐 Transient 가 담을 수 있는 4 단계를 넘지 않도록 중첩해야 함
+pub struct Signal<T>(PhantomData<fn() -> T>);
+
+impl<T> Debug for Signal<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Signal")
+    }
+}
+
+impl<T> Default for Signal<T> {
+    fn default() -> Self { Self(PhantomData) }
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for Signal<T> {}
+
+impl<T> Serialize for Signal<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Signal<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        <()>::deserialize(deserializer)?;
+        Ok(Self::default())
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod signal {
+    use super::*;
+
+    const SIGNAL_ID_DELTA: super::IdDelta = 1;
+    const SIGNAL_ID_DELTA_END: super::IdDelta = SIGNAL_ID_DELTA + 1;
+
+    #[derive(Debug, Clone)]
+    pub enum Message<T: State> {
+        Signal(T),
+        State(Signal<T>),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter<T: State> {
+        callback: super::Callback<Signal<T>>,
+        pub signal: super::Callback<Signal<T>>,
+        sequence: Arc<AtomicU32>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser<T: State> {
+        lookup: super::Lookup<Signal<T>>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n, T: State> {
+        accesser: &'n Accesser<T>,
+        emitter: &'n Emitter<T>,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+    }
+
+    impl<T: State> super::State for Signal<T> {
+        const NODE_SIZE: super::IdSize = SIGNAL_ID_DELTA_END;
+        const NODE_ALT_SIZE: super::AltSize = 1;
+
+        type Message = signal::Message<T>;
+        type Emitter = signal::Emitter<T>;
+        type Accesser = signal::Accesser<T>;
+        type Node<'n> = signal::Node<'n, T>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            signal::Message::State(self)
+        }
+    }
+
+    impl<T: State> super::Fallback for Signal<T> {
+        fn fallback(
+            _node: Node<'_, T>,
+            message: Message<T>,
+            _delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::Signal(_) => (),
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl<T: State> super::System for Signal<T> {
+
+    }
+
+    impl<T: State> super::Message for Message<T> {
+        type State = Signal<T>;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    SIGNAL_ID_DELTA..SIGNAL_ID_DELTA_END => Ok(Message::Signal(
+                        packet.decode(depth)?
+                    )),
+                    id_delta => Err(super::PacketError::new(
+                        packet.clone(),
+                        Some(id_delta),
+                        Some(depth),
+                        format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                    )),
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::Signal(value) => super::Packet::new(key, super::State::to_payload(value)),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, _state: &mut Signal<T>) {
+
+        }
+    }
+
+    impl<T: State> super::Emitter<Signal<T>> for Emitter<T> {
+        fn callback(&self) -> &super::Callback<Signal<T>> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Signal<T>>) -> Self {
+            Self {
+                signal: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    SIGNAL_ID_DELTA,
+                    |_, message| message,
+                ),
+                sequence: Arc::new(AtomicU32::new(0)),
+                callback,
+            }
+        }
+    }
+
+    impl<T: State> super::Accesser<Signal<T>> for Accesser<T> {
+        fn lookup(&self) -> &super::Lookup<Signal<T>> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: super::LookupBuilder<CS, Signal<T>>) -> Self {
+            Self {
+                lookup: builder.build(|state| state.cloned()),
+            }
+        }
+    }
+
+    impl<'n, T: State> super::Node<'n, Signal<T>> for Node<'n, T> {
+        fn accesser(&self) -> &Accesser<T> { self.accesser }
+        fn emitter(&self) -> &Emitter<T> { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n, T: State> super::NewNode<'n, Signal<T>> for Node<'n, T> {
+        fn new(
+            accesser: &'n Accesser<T>,
+            emitter: &'n Emitter<T>,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+            }
+        }
+    }
+
+    impl<'n, T: State> Node<'n, T> {
+        // 하나의 입력에서 이어진 연쇄 안에서 같은 Key 로 여러번 emit 되면 중복 제거되어 처음 한번만 전달됨
+        pub fn emit_signal(&self, value: T) {
+            EmitError::report(self.try_emit_signal(value));
+        }
+
+        pub fn try_emit_signal(&self, value: T) -> Result<(), EmitError> {
+            self.emitter.signal.try_emit(self.callback_mode, self.transient, Message::Signal(value))
+        }
+
+        // 매 emit 마다 다른 alt index 를 사용하여 연쇄 안에서도 중복 제거 없이 모두 전달됨
+        pub fn emit_each(&self, value: T) {
+            EmitError::report(self.try_emit_each(value));
+        }
+
+        pub fn try_emit_each(&self, value: T) -> Result<(), EmitError> {
+            use crate::ext::Node;
+
+            let sequence = self.emitter.sequence.fetch_add(1, Ordering::Relaxed);
+            let transient = self.transient.alt(self.consist().alt_depth(), sequence);
+
+            self.emitter.signal.try_emit(self.callback_mode, &transient, Message::Signal(value))?;
+
+            Ok(())
+        }
+    }
+}
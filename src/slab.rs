@@ -0,0 +1,496 @@
+use std::{collections::BTreeSet, sync::Mutex, vec::IntoIter};
+use serde::{Deserialize, Serialize};
+use crate::ext::*;
+
+pub type SlabIndex = u16;
+pub type Generation = u16;
+
+// 슬롯 위치와 세대를 함께 가지는 handle
+// 슬롯이 제거되면 세대가 증가하므로 이전 handle 로 보낸 메시지는 무시됨
+// 세대가 Generation::MAX 에 이르면 슬롯은 더 이상 재사용되지 않아 같은 handle 이 다시 나오지 않음
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Handle {
+    pub index: SlabIndex,
+    pub generation: Generation,
+}
+
+impl Handle {
+    // (index, generation) 을 하나의 AltIndex 로 합쳐 한 단계의 alt 만 사용
+    pub fn to_alt(&self) -> AltIndex {
+        ((self.generation as AltIndex) << SlabIndex::BITS) | self.index as AltIndex
+    }
+
+    pub fn from_alt(index: AltIndex) -> Self {
+        Self {
+            index: index as SlabIndex,
+            generation: (index >> SlabIndex::BITS) as Generation,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Entry<I> {
+    generation: Generation,
+    // 값을 삽입한 replica, 같은 handle 에 동시에 삽입되면 더 큰 replica 의 값이 남음
+    #[serde(default)]
+    owner: ReplicaId,
+    value: Option<I>,
+}
+
+impl<I> Entry<I> {
+    fn is_retired(&self) -> bool {
+        self.generation == Generation::MAX
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Slab<I> {
+    entries: Vec<Entry<I>>,
+    #[serde(skip)]
+    reserved: Mutex<BTreeSet<SlabIndex>>,
+}
+
+impl<I> Default for Slab<I> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            reserved: Mutex::default(),
+        }
+    }
+}
+
+impl<I: Clone> Clone for Slab<I> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            reserved: Mutex::new(self.reserved.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl<I> Slab<I> {
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.value.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(|entry| entry.value.is_none())
+    }
+
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.get(handle).is_some()
+    }
+
+    pub fn get(&self, handle: Handle) -> Option<&I> {
+        self.entries
+            .get(handle.index as usize)
+            .filter(|entry| entry.generation == handle.generation)
+            .and_then(|entry| entry.value.as_ref())
+    }
+
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut I> {
+        self.entries
+            .get_mut(handle.index as usize)
+            .filter(|entry| entry.generation == handle.generation)
+            .and_then(|entry| entry.value.as_mut())
+    }
+
+    pub fn handles(&self) -> impl Iterator<Item = Handle> + '_ {
+        self.entries.iter().enumerate()
+            .filter(|(_, entry)| entry.value.is_some())
+            .map(|(index, entry)| Handle {
+                index: index as SlabIndex,
+                generation: entry.generation,
+            })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Handle, &I)> + '_ {
+        self.entries.iter().enumerate()
+            .filter_map(|(index, entry)| entry.value.as_ref().map(|value| (
+                Handle {
+                    index: index as SlabIndex,
+                    generation: entry.generation,
+                },
+                value,
+            )))
+    }
+
+    // 비어있는 슬롯 중 아직 이 replica 가 예약하지 않은 가장 앞의 슬롯을 예약
+    pub fn prepare_insert(&self) -> Option<Handle> {
+        let mut reserved = self.reserved.lock().unwrap();
+
+        let handle = self.entries.iter().enumerate()
+            .filter(|(index, entry)| {
+                entry.value.is_none() && !entry.is_retired() && !reserved.contains(&(*index as SlabIndex))
+            })
+            .map(|(index, entry)| Handle {
+                index: index as SlabIndex,
+                generation: entry.generation,
+            })
+            .next()
+            .or_else(|| {
+                (self.entries.len()..=SlabIndex::MAX as usize)
+                    .find(|index| !reserved.contains(&(*index as SlabIndex)))
+                    .map(|index| Handle {
+                        index: index as SlabIndex,
+                        generation: 0,
+                    })
+            })?;
+
+        reserved.insert(handle.index);
+
+        Some(handle)
+    }
+
+    // handle 의 세대가 다르거나 슬롯이 더 큰 replica 의 값으로 사용 중이면 삽입하지 않음
+    // 두 replica 가 같은 handle 에 동시에 삽입해도 적용 순서와 무관하게 더 큰 replica 의 값으로 수렴함
+    pub fn insert(&mut self, handle: Handle, owner: ReplicaId, value: I) -> bool {
+        self.reserved.get_mut().unwrap().remove(&handle.index);
+
+        if handle.generation == Generation::MAX {
+            return false;
+        }
+
+        let index = handle.index as usize;
+
+        if self.entries.len() <= index {
+            self.entries.resize_with(index + 1, || Entry {
+                generation: 0,
+                owner: 0,
+                value: None,
+            });
+        }
+
+        let entry = &mut self.entries[index];
+
+        if entry.generation == handle.generation 
+        && (entry.value.is_none() || entry.owner < owner) {
+            entry.owner = owner;
+            entry.value = Some(value);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn remove(&mut self, handle: Handle) -> Option<I> {
+        let entry = self.entries
+            .get_mut(handle.index as usize)
+            .filter(|entry| entry.generation == handle.generation && entry.value.is_some())?;
+
+        entry.generation = entry.generation.saturating_add(1);
+        entry.value.take()
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod slab {
+    use super::*;
+
+    const INSERT_ID_DELTA: super::IdDelta = 1;
+    const INSERT_ID_DELTA_END: super::IdDelta = INSERT_ID_DELTA + 1;
+    const REMOVE_ID_DELTA: super::IdDelta = INSERT_ID_DELTA_END;
+    const REMOVE_ID_DELTA_END: super::IdDelta = REMOVE_ID_DELTA + 1;
+    const ITEM_ID_DELTA: super::IdDelta = REMOVE_ID_DELTA_END;
+
+    #[derive(Debug, Clone)]
+    pub enum Message<I: System> {
+        Insert(Handle, ReplicaId, I),
+        Remove(Handle),
+        Item(Handle, <I as super::State>::Message),
+        State(Slab<I>),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter<I: System> {
+        callback: super::Callback<Slab<I>>,
+        pub insert: super::Callback<Slab<I>>,
+        pub remove: super::Callback<Slab<I>>,
+        pub item: <I as super::State>::Emitter,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser<I: System> {
+        lookup: super::Lookup<Slab<I>>,
+        lookup_len: super::Lookup<usize>,
+        lookup_handles: super::Lookup<Vec<Handle>>,
+        query_contains: super::Query<Handle, bool>,
+        query_insert: super::Query<(), (Handle, ReplicaId)>,
+        pub item: <I as super::State>::Accesser,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n, I: System> {
+        accesser: &'n Accesser<I>,
+        emitter: &'n Emitter<I>,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+        pub item: <I as super::State>::Node<'n>,
+    }
+
+    impl<I: System> super::State for Slab<I> {
+        const NODE_SIZE: super::IdSize = ITEM_ID_DELTA + <I as super::State>::NODE_SIZE;
+        const NODE_ALT_SIZE: super::AltSize = 1;
+
+        type Message = slab::Message<I>;
+        type Emitter = slab::Emitter<I>;
+        type Accesser = slab::Accesser<I>;
+        type Node<'n> = slab::Node<'n, I>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            slab::Message::State(self)
+        }
+    }
+
+    impl<I: System> super::Fallback for Slab<I> {
+        fn fallback(
+            node: Node<'_, I>,
+            message: Message<I>,
+            delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::Insert(_, _, _) => (),
+                Message::Remove(_) => (),
+                Message::Item(handle, message) => {
+                    if node.contains(handle) {
+                        I::handle(
+                            node.item(handle).node(),
+                            message,
+                            delta,
+                        )
+                    }
+                },
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl<I: System> super::System for Slab<I> {
+
+    }
+
+    impl<I: System> super::Message for Message<I> {
+        type State = Slab<I>;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    INSERT_ID_DELTA..INSERT_ID_DELTA_END => {
+                        let (handle, owner, value) = packet.decode(depth)?;
+                        Ok(Message::Insert(handle, owner, value))
+                    },
+                    REMOVE_ID_DELTA..REMOVE_ID_DELTA_END => Ok(Message::Remove(
+                        packet.decode(depth)?
+                    )),
+                    ITEM_ID_DELTA.. => Ok(Message::Item(
+                        Handle::from_alt(packet.key().transient().index(parent_key.consist().alt_depth())),
+                        <I as super::State>::Message::from_packet(
+                            packet,
+                            super::Key::new(
+                                parent_key
+                                    .consist()
+                                    .access(ITEM_ID_DELTA, <Slab<I>>::NODE_ALT_SIZE),
+                                parent_key.transient(),
+                            ),
+                            depth + 1,
+                        )?
+                    )),
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::Insert(handle, owner, value) => super::Packet::new(key, super::Payload::from_value(&(handle, owner, value))),
+                Self::Remove(handle) => super::Packet::new(key, super::Payload::from_value(handle)),
+                Self::Item(handle, message) => message.to_packet(key.alt(handle.to_alt())),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Slab<I>) {
+            match self {
+                Self::Insert(handle, owner, value) => { state.insert(*handle, *owner, value.clone()); },
+                Self::Remove(handle) => { state.remove(*handle); },
+                Self::Item(handle, message) => {
+                    if let Some(item) = state.get_mut(*handle) {
+                        message.apply_to(item);
+                    }
+                },
+                Self::State(new_state) => state.entries = new_state.entries.clone(),
+            }
+        }
+    }
+
+    impl<I: System> super::Emitter<Slab<I>> for Emitter<I> {
+        fn callback(&self) -> &super::Callback<Slab<I>> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Slab<I>>) -> Self {
+            Self {
+                insert: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    INSERT_ID_DELTA,
+                    |_, message| message,
+                ),
+                remove: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    REMOVE_ID_DELTA,
+                    |_, message| message,
+                ),
+                item: super::Emitter::new(super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    ITEM_ID_DELTA,
+                    |index, message| Message::Item(Handle::from_alt(index), message),
+                )),
+                callback,
+            }
+        }
+    }
+
+    impl<I: System> super::Accesser<Slab<I>> for Accesser<I> {
+        fn lookup(&self) -> &super::Lookup<Slab<I>> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: super::LookupBuilder<CS, Slab<I>>) -> Self {
+            Self {
+                item: super::Accesser::new(builder.access(
+                    |state, index| state.and_then(|state| state.get(Handle::from_alt(index))),
+                    ITEM_ID_DELTA,
+                )),
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_len: builder.clone().build(|state| state.map(|state| state.len())),
+                lookup_handles: builder.clone().build(|state| state.map(|state| state.handles().collect())),
+                query_contains: builder.clone().build_query(|state, handle| {
+                    state.map(|state| state.contains(*handle))
+                }),
+                query_insert: builder.build_replica_query(|state, replica, _| {
+                    state.and_then(|state| state.prepare_insert()).map(|handle| (handle, replica.id()))
+                }),
+            }
+        }
+    }
+
+    impl<'n, I: System> super::Node<'n, Slab<I>> for Node<'n, I> {
+        fn accesser(&self) -> &Accesser<I> { self.accesser }
+        fn emitter(&self) -> &Emitter<I> { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n, I: System> super::NewNode<'n, Slab<I>> for Node<'n, I> {
+        fn new(
+            accesser: &'n Accesser<I>,
+            emitter: &'n Emitter<I>,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+                item: super::NewNode::new(
+                    &accesser.item,
+                    &emitter.item,
+                    callback_mode,
+                    transient,
+                ),
+            }
+        }
+    }
+
+    impl<'n, I: System> Node<'n, I> {
+        // 슬롯이 모두 사용 중이면 None 을 반환하고 emit 하지 않음
+        pub fn emit_insert(&self, value: I) -> Option<Handle> {
+            self.try_emit_insert(value).unwrap_or_else(|err| {
+                EmitError::report(Err(err));
+                None
+            })
+        }
+
+        pub fn try_emit_insert(&self, value: I) -> Result<Option<Handle>, EmitError> {
+            let Some((handle, owner)) = self.accesser.query_insert.get(self.transient, &()) else { 
+                return Ok(None);
+            };
+
+            self.emitter.insert.try_emit(
+                self.callback_mode,
+                &self.transient_of(handle),
+                Message::Insert(handle, owner, value),
+            )?;
+
+            Ok(Some(handle))
+        }
+
+        pub fn emit_remove(&self, handle: Handle) {
+            EmitError::report(self.try_emit_remove(handle));
+        }
+
+        pub fn try_emit_remove(&self, handle: Handle) -> Result<(), EmitError> {
+            self.emitter.remove.try_emit(
+                self.callback_mode,
+                &self.transient_of(handle),
+                Message::Remove(handle),
+            )
+        }
+
+        pub fn items(&self) -> IntoIter<(Handle, NodeAlt<'_, I>)> {
+            let handles = self.accesser.lookup_handles.get(self.transient).unwrap_or_default();
+            let mut result = Vec::new();
+
+            for handle in handles {
+                result.push((handle, self.item(handle)))
+            }
+
+            result.into_iter()
+        }
+
+        pub fn len(&self) -> usize {
+            self.accesser.lookup_len.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        pub fn contains(&self, handle: Handle) -> bool {
+            self.accesser.query_contains.get(self.transient, &handle).unwrap_or_default()
+        }
+
+        pub fn item(&self, handle: Handle) -> NodeAlt<'_, I> {
+            use crate::ext::Node;
+            self.item.alt(self.consist(), handle.to_alt())
+        }
+
+        // 같은 Tick 의 여러 삽입과 제거가 중복 제거되지 않도록 handle 을 alt index 로 사용
+        fn transient_of(&self, handle: Handle) -> super::Transient {
+            use crate::ext::Node;
+            self.transient.alt(self.consist().alt_depth(), handle.to_alt())
+        }
+    }
+}
@@ -1,6 +1,129 @@
+use std::time::{Duration, SystemTime};
+use serde::{Deserialize, Serialize};
 use crate::ext::*;
 use crate::frand_node;
 
+// SystemTime 은 Default 가 없어 UNIX_EPOCH 를 기본값으로 하는 Timestamp 로 감싸서 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(pub SystemTime);
+
+impl Default for Timestamp {
+    fn default() -> Self { Self(SystemTime::UNIX_EPOCH) }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self { Self(time) }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self { timestamp.0 }
+}
+
+impl Timestamp {
+    pub fn now() -> Self { Self(SystemTime::now()) }
+}
+
+// time 의 날짜 타입도 Default 가 없어 UNIX_EPOCH 를 기본값으로 하는 타입으로 감싸서 사용
+#[cfg(feature = "time")]
+macro_rules! impl_time_wrapper {
+    ( $( $name: ident($ty: ty) = $default: expr ),+ $(,)? ) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+            #[serde(transparent)]
+            pub struct $name(pub $ty);
+
+            impl Default for $name {
+                fn default() -> Self { Self($default) }
+            }
+
+            impl From<$ty> for $name {
+                fn from(time: $ty) -> Self { Self(time) }
+            }
+
+            impl From<$name> for $ty {
+                fn from(time: $name) -> Self { time.0 }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "time")]
+impl_time_wrapper!{
+    OffsetDateTime(time::OffsetDateTime) = time::OffsetDateTime::UNIX_EPOCH,
+    PrimitiveDateTime(time::PrimitiveDateTime) = time::PrimitiveDateTime::new(
+        time::OffsetDateTime::UNIX_EPOCH.date(),
+        time::OffsetDateTime::UNIX_EPOCH.time(),
+    ),
+    Date(time::Date) = time::OffsetDateTime::UNIX_EPOCH.date(),
+}
+
+#[cfg(feature = "time")]
+impl OffsetDateTime {
+    pub fn now_utc() -> Self { Self(time::OffsetDateTime::now_utc()) }
+}
+
+// [MIN, MAX] 범위의 f32 를 BITS 비트의 정수 단계로 양자화하여 보관
+// 로컬 상태도 양자화된 값을 보관하므로 모든 replica 가 같은 값을 가짐
+// 0 < BITS <= 32, MIN < MAX 가 아니면 컴파일 되지 않음
+///
+/// ```compile_fail
+/// let _ = frand_node::ext::Quantized::<33, 0, 1>::new(0.5);
+/// ```
+///
+/// ```compile_fail
+/// let _ = frand_node::ext::Quantized::<8, 1, 1>::from_step(0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Terminal)]
+#[serde(transparent)]
+pub struct Quantized<const BITS: u32, const MIN: i32, const MAX: i32>(u32);
+
+impl<const BITS: u32, const MIN: i32, const MAX: i32> Default for Quantized<BITS, MIN, MAX> {
+    fn default() -> Self { Self::new(0.0) }
+}
+
+impl<const BITS: u32, const MIN: i32, const MAX: i32> From<f32> for Quantized<BITS, MIN, MAX> {
+    fn from(value: f32) -> Self { Self::new(value) }
+}
+
+impl<const BITS: u32, const MIN: i32, const MAX: i32> From<Quantized<BITS, MIN, MAX>> for f32 {
+    fn from(quantized: Quantized<BITS, MIN, MAX>) -> Self { quantized.value() }
+}
+
+impl<const BITS: u32, const MIN: i32, const MAX: i32> Quantized<BITS, MIN, MAX> {
+    // 값을 만들거나 읽는 모든 경로가 STEPS 를 거치므로 잘못된 인자는 여기서 거부됨
+    pub const STEPS: u32 = {
+        assert!(0 < BITS && BITS <= 32 && MIN < MAX, "Quantized: requires 0 < BITS <= 32 and MIN < MAX");
+        u32::MAX >> (32 - BITS)
+    };
+
+    pub fn new(value: f32) -> Self {
+        let ratio = (value - MIN as f32) / (MAX as f32 - MIN as f32);
+        let ratio = if ratio.is_nan() { 0.0 } else { ratio.clamp(0.0, 1.0) };
+
+        Self((ratio as f64 * Self::STEPS as f64).round() as u32)
+    }
+
+    pub fn from_step(step: u32) -> Self {
+        Self(step.min(Self::STEPS))
+    }
+
+    pub fn step(&self) -> u32 { self.0 }
+
+    pub fn value(&self) -> f32 {
+        let ratio = self.0.min(Self::STEPS) as f64 / Self::STEPS as f64;
+        (MIN as f64 + ratio * (MAX as f64 - MIN as f64)) as f32
+    }
+}
+
+impl<'n, const BITS: u32, const MIN: i32, const MAX: i32> terminal::Node<'n, Quantized<BITS, MIN, MAX>> {
+    pub fn v(&self) -> f32 {
+        use crate::ext::Node;
+        self.clone_state().unwrap_or_default().value()
+    }
+}
+
+#[allow(clippy::module_inception)]
 pub mod terminal {
     pub use super::*;
 
@@ -51,7 +174,7 @@ pub mod terminal {
         fn accesser(&self) -> &S::Accesser { self.accesser }
         fn emitter(&self) -> &S::Emitter { self.emitter }
         fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
-        fn transient(&self) -> &Transient { &self.transient }
+        fn transient(&self) -> &Transient { self.transient }
     }
     
     impl<'n, S: System> NewNode<'n, S> for Node<'n, S> {
@@ -110,9 +233,9 @@ macro_rules! impl_terminal_message_for {
                 fn from_packet(
                     packet: &frand_node::ext::Packet,
                     _parent_key: Key,
-                    _depth: usize,                 
+                    depth: usize,                 
                 ) -> Result<Self> {
-                    Ok(packet.payload().to_state())
+                    packet.decode(depth)
                 }     
 
                 fn to_packet(
@@ -123,7 +246,7 @@ macro_rules! impl_terminal_message_for {
                 }
 
                 fn apply_to(&self, state: &mut Self::State) {
-                    *state = *self;
+                    *state = self.clone();
                 }
             }
         )*      
@@ -154,4 +277,32 @@ impl_terminal_for!{
     u8, u16, u32, u64, u128, 
     f32, f64,
     char, bool, (),
-}
\ No newline at end of file
+}
+
+impl_terminal_for!{ 
+    Duration, Timestamp,
+}
+
+#[cfg(feature = "chrono")]
+impl_terminal_for!{ 
+    chrono::DateTime<chrono::Utc>, 
+    chrono::DateTime<chrono::FixedOffset>,
+    chrono::NaiveDate, chrono::NaiveTime, chrono::NaiveDateTime,
+    chrono::TimeDelta,
+}
+
+#[cfg(feature = "time")]
+impl_terminal_for!{ 
+    time::Duration,
+    OffsetDateTime, PrimitiveDateTime, Date,
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_terminal_for!{ 
+    rust_decimal::Decimal,
+}
+
+#[cfg(feature = "num-bigint")]
+impl_terminal_for!{ 
+    num_bigint::BigInt, num_bigint::BigUint,
+}
@@ -0,0 +1,463 @@
+use std::{collections::BTreeSet, fmt::Display, ops::Range, sync::atomic::{AtomicU64, Ordering}};
+use serde::{Deserialize, Serialize};
+use crate::{ext::*, replica::new_replica};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct OpId {
+    pub counter: u64,
+    pub replica: ReplicaId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Insert {
+    pub id: OpId,
+    pub after: Option<OpId>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Element {
+    id: OpId,
+    // 삽입될 때 바로 앞에 있던 문자로, State 를 병합할 때 같은 위치에 다시 삽입하는 데 사용
+    #[serde(default)]
+    after: Option<OpId>,
+    value: char,
+    deleted: bool,
+}
+
+// RGA 기반의 문자열
+// 각 문자는 (counter, replica) 로 식별되며 Insert 와 Delete 는 적용 순서와 무관하게 같은 결과로 수렴함
+// State 메시지는 덮어쓰지 않고 병합됨
+// node 에서 emit 한 Insert 는 Component 의 replica id 를 사용함
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Text {
+    elements: Vec<Element>,
+    clock: u64,
+    pending_inserts: Vec<Insert>,
+    pending_deletes: BTreeSet<OpId>,
+    #[serde(skip)]
+    issued: AtomicU64,
+}
+
+impl Default for Text {
+    fn default() -> Self {
+        Self {
+            elements: Vec::new(),
+            clock: 0,
+            pending_inserts: Vec::new(),
+            pending_deletes: BTreeSet::new(),
+            issued: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Clone for Text {
+    fn clone(&self) -> Self {
+        Self {
+            elements: self.elements.clone(),
+            clock: self.clock,
+            pending_inserts: self.pending_inserts.clone(),
+            pending_deletes: self.pending_deletes.clone(),
+            issued: AtomicU64::new(self.issued.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl Display for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.chars().try_for_each(|value| write!(f, "{value}"))
+    }
+}
+
+impl From<&str> for Text {
+    fn from(text: &str) -> Self {
+        let mut result = Self::default();
+        result.apply_insert(&result.prepare_insert(new_replica(), 0, text));
+        result
+    }
+}
+
+impl Text {
+    pub fn clock(&self) -> u64 { self.clock }
+    pub fn len(&self) -> usize { self.chars().count() }
+    pub fn is_empty(&self) -> bool { self.chars().next().is_none() }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.elements.iter()
+        .filter(|element| !element.deleted)
+        .map(|element| element.value)
+    }
+
+    // node 에서 emit 할 때는 Component 의 replica id 로 발행
+    pub fn prepare_insert(&self, replica: ReplicaId, index: usize, text: &str) -> Insert {
+        let after = index.checked_sub(1).and_then(|index| self.visible(index)).map(|element| element.id);
+        let count = text.chars().count() as u64;
+
+        let issued = self.issued.fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |issued| Some(issued.max(self.clock) + count),
+        ).unwrap_or_default();
+
+        Insert {
+            id: OpId {
+                counter: issued.max(self.clock) + 1,
+                replica,
+            },
+            after,
+            text: text.to_string(),
+        }
+    }
+
+    pub fn prepare_delete(&self, range: Range<usize>) -> Vec<OpId> {
+        self.elements.iter()
+        .filter(|element| !element.deleted)
+        .skip(range.start)
+        .take(range.end.saturating_sub(range.start))
+        .map(|element| element.id)
+        .collect()
+    }
+
+    pub fn apply_insert(&mut self, insert: &Insert) {
+        if !self.integrate(insert) {
+            if !self.pending_inserts.contains(insert) {
+                self.pending_inserts.push(insert.clone());
+            }
+            return;
+        }
+
+        while let Some(index) = self.pending_inserts.iter()
+            .position(|pending| self.is_ready(pending))
+        {
+            let pending = self.pending_inserts.remove(index);
+            self.integrate(&pending);
+        }
+    }
+
+    pub fn apply_delete(&mut self, ids: &[OpId]) {
+        for id in ids {
+            match self.position(id) {
+                Some(position) => self.elements[position].deleted = true,
+                None => { self.pending_deletes.insert(*id); },
+            }
+        }
+    }
+
+    // 다른 replica 의 State 를 병합하며 양쪽의 Insert 와 Delete 를 모두 유지
+    pub fn merge(&mut self, other: &Text) {
+        for element in &other.elements {
+            self.apply_insert(&Insert {
+                id: element.id,
+                after: element.after,
+                text: element.value.to_string(),
+            });
+        }
+
+        for insert in &other.pending_inserts {
+            self.apply_insert(insert);
+        }
+
+        let deletes: Vec<OpId> = other.elements.iter()
+            .filter(|element| element.deleted)
+            .map(|element| element.id)
+            .chain(other.pending_deletes.iter().copied())
+            .collect();
+
+        self.apply_delete(&deletes);
+        self.clock = self.clock.max(other.clock);
+    }
+
+    fn visible(&self, index: usize) -> Option<&Element> {
+        self.elements.iter()
+        .filter(|element| !element.deleted)
+        .nth(index)
+    }
+
+    fn position(&self, id: &OpId) -> Option<usize> {
+        self.elements.iter().position(|element| element.id == *id)
+    }
+
+    fn is_ready(&self, insert: &Insert) -> bool {
+        insert.after.is_none_or(|after| self.position(&after).is_some())
+    }
+
+    fn integrate(&mut self, insert: &Insert) -> bool {
+        if !self.is_ready(insert) {
+            return false;
+        }
+
+        let mut after = insert.after;
+
+        for (offset, value) in insert.text.chars().enumerate() {
+            let id = OpId {
+                counter: insert.id.counter + offset as u64,
+                replica: insert.id.replica,
+            };
+
+            if self.position(&id).is_none() {
+                let mut position = after
+                    .and_then(|after| self.position(&after))
+                    .map_or(0, |position| position + 1);
+
+                // 같은 위치에 동시에 삽입된 더 큰 id 의 문자들을 건너뛰어 모든 replica 에서 같은 순서를 유지
+                while self.elements.get(position).is_some_and(|element| element.id > id) {
+                    position += 1;
+                }
+
+                self.elements.insert(position, Element {
+                    id,
+                    after,
+                    value,
+                    deleted: self.pending_deletes.remove(&id),
+                });
+
+                self.clock = self.clock.max(id.counter);
+            }
+
+            after = Some(id);
+        }
+
+        true
+    }
+}
+
+#[allow(clippy::module_inception)]
+pub mod text {
+    use super::*;
+
+    const INSERT_ID_DELTA: super::IdDelta = 1;
+    const INSERT_ID_DELTA_END: super::IdDelta = INSERT_ID_DELTA + 1;
+    const DELETE_ID_DELTA: super::IdDelta = INSERT_ID_DELTA_END;
+    const DELETE_ID_DELTA_END: super::IdDelta = DELETE_ID_DELTA + 1;
+
+    #[derive(Debug, Clone)]
+    pub enum Message {
+        Insert(super::Insert),
+        Delete(Vec<OpId>),
+        State(Text),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Emitter {
+        callback: super::Callback<Text>,
+        pub insert: super::Callback<Text>,
+        pub delete: super::Callback<Text>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Accesser {
+        lookup: super::Lookup<Text>,
+        lookup_string: super::Lookup<String>,
+        lookup_len: super::Lookup<usize>,
+        query_insert: super::Query<(usize, String), super::Insert>,
+        query_delete: super::Query<Range<usize>, Vec<OpId>>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Node<'n> {
+        accesser: &'n Accesser,
+        emitter: &'n Emitter,
+        callback_mode: &'n CallbackMode,
+        transient: &'n super::Transient,
+    }
+
+    impl super::State for Text {
+        const NODE_SIZE: super::IdSize = DELETE_ID_DELTA_END;
+        const NODE_ALT_SIZE: super::AltSize = 0;
+
+        type Message = text::Message;
+        type Emitter = text::Emitter;
+        type Accesser = text::Accesser;
+        type Node<'n> = text::Node<'n>;
+
+        fn from_payload(payload: &super::Payload) -> Self {
+            super::Payload::to_state(payload)
+        }
+
+        fn to_payload(&self) -> super::Payload {
+            super::Payload::from_state(self)
+        }
+
+        fn into_message(self) -> Self::Message {
+            text::Message::State(self)
+        }
+    }
+
+    impl super::Fallback for Text {
+        fn fallback(
+            _node: Node<'_>,
+            message: Message,
+            _delta: Option<std::time::Duration>,
+        ) {
+            match message {
+                Message::Insert(_) => (),
+                Message::Delete(_) => (),
+                Message::State(_) => (),
+            }
+        }
+    }
+
+    impl super::System for Text {
+
+    }
+
+    impl super::Message for Message {
+        type State = Text;
+
+        #[allow(clippy::needless_question_mark)]
+        fn from_packet(
+            packet: &super::Packet,
+            parent_key: super::Key,
+            depth: usize,
+        ) -> super::Result<Self> {
+            Ok(
+                match packet.key().consist().id() - parent_key.consist().id() {
+                    0 => Ok(Self::State(
+                        packet.decode(depth)?
+                    )),
+                    INSERT_ID_DELTA..INSERT_ID_DELTA_END => Ok(Message::Insert(
+                        packet.decode(depth)?
+                    )),
+                    DELETE_ID_DELTA..DELETE_ID_DELTA_END => Ok(Message::Delete(
+                        packet.decode(depth)?
+                    )),
+                    id_delta => Err(super::PacketError::new(
+                        packet.clone(),
+                        Some(id_delta),
+                        Some(depth),
+                        format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                    )),
+                }?,
+            )
+        }
+
+        fn to_packet(&self, key: super::Key) -> super::Packet {
+            match self {
+                Self::Insert(insert) => super::Packet::new(key, super::Payload::from_value(insert)),
+                Self::Delete(ids) => super::Packet::new(key, super::Payload::from_value(ids)),
+                Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+            }
+        }
+
+        fn apply_to(&self, state: &mut Text) {
+            match self {
+                Self::Insert(insert) => state.apply_insert(insert),
+                Self::Delete(ids) => state.apply_delete(ids),
+                Self::State(new_state) => state.merge(new_state),
+            }
+        }
+    }
+
+    impl super::Emitter<Text> for Emitter {
+        fn callback(&self) -> &super::Callback<Text> {
+            &self.callback
+        }
+
+        fn new(callback: super::Callback<Text>) -> Self {
+            Self {
+                insert: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    INSERT_ID_DELTA,
+                    |_, message| message,
+                ),
+                delete: super::Callback::access(
+                    *callback.consist(),
+                    callback.callback().clone(),
+                    callback.process().clone(),
+                    DELETE_ID_DELTA,
+                    |_, message| message,
+                ),
+                callback,
+            }
+        }
+    }
+
+    impl super::Accesser<Text> for Accesser {
+        fn lookup(&self) -> &super::Lookup<Text> {
+            &self.lookup
+        }
+
+        fn new<CS: System>(builder: super::LookupBuilder<CS, Text>) -> Self {
+            Self {
+                lookup: builder.clone().build(|state| state.cloned()),
+                lookup_string: builder.clone().build(|state| state.map(|state| state.to_string())),
+                lookup_len: builder.clone().build(|state| state.map(|state| state.len())),
+                query_insert: builder.clone().build_replica_query(|state, replica, (index, text)| {
+                    state.map(|state| state.prepare_insert(replica.id(), *index, text))
+                }),
+                query_delete: builder.build_query(|state, range| {
+                    state.map(|state| state.prepare_delete(range.clone()))
+                }),
+            }
+        }
+    }
+
+    impl<'n> super::Node<'n, Text> for Node<'n> {
+        fn accesser(&self) -> &Accesser { self.accesser }
+        fn emitter(&self) -> &Emitter { self.emitter }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
+    }
+
+    impl<'n> super::NewNode<'n, Text> for Node<'n> {
+        fn new(
+            accesser: &'n Accesser,
+            emitter: &'n Emitter,
+            callback_mode: &'n CallbackMode,
+            transient: &'n super::Transient,
+        ) -> Self {
+            Self {
+                accesser,
+                emitter,
+                callback_mode,
+                transient,
+            }
+        }
+    }
+
+    impl<'n> Node<'n> {
+        pub fn emit_insert(&self, index: usize, text: &str) {
+            EmitError::report(self.try_emit_insert(index, text));
+        }
+
+        pub fn try_emit_insert(&self, index: usize, text: &str) -> Result<(), EmitError> {
+            if text.is_empty() {
+                return Ok(());
+            }
+
+            if let Some(insert) = self.accesser.query_insert.get(self.transient, &(index, text.to_string())) {
+                self.emitter.insert.try_emit(self.callback_mode, self.transient, Message::Insert(insert))?;
+            }
+
+            Ok(())
+        }
+
+        pub fn emit_delete(&self, range: Range<usize>) {
+            EmitError::report(self.try_emit_delete(range));
+        }
+
+        pub fn try_emit_delete(&self, range: Range<usize>) -> Result<(), EmitError> {
+            if let Some(ids) = self.accesser.query_delete.get(self.transient, &range) {
+                if !ids.is_empty() {
+                    self.emitter.delete.try_emit(self.callback_mode, self.transient, Message::Delete(ids))?;
+                }
+            }
+
+            Ok(())
+        }
+
+        pub fn text(&self) -> String {
+            self.accesser.lookup_string.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn len(&self) -> usize {
+            self.accesser.lookup_len.get(self.transient).unwrap_or_default()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::ops::Deref;
+use crate::ext::*;
+
+const fn id_delta(node_sizes: &[IdSize], index: usize) -> IdDelta {
+    let mut id_delta = 1;
+    let mut i = 0;
+
+    while i < index {
+        id_delta += node_sizes[i];
+        i += 1;
+    }
+
+    id_delta
+}
+
+macro_rules! impl_tuple_node {
+    ( $( $module: ident { $($index: tt $ty: ident $variant: ident),+ $(,)? } )+ ) => {
+        $(
+            pub mod $module {
+                use super::*;
+
+                #[derive(Debug, Clone)]
+                pub enum Message<$($ty: System),+> {
+                    $($variant(<$ty as super::State>::Message),)+
+                    State(($($ty,)+)),
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Emitter<$($ty: System),+> {
+                    callback: super::Callback<($($ty,)+)>,
+                    pub items: ($(<$ty as super::State>::Emitter,)+),
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Accesser<$($ty: System),+> {
+                    lookup: super::Lookup<($($ty,)+)>,
+                    pub items: ($(<$ty as super::State>::Accesser,)+),
+                }
+
+                #[derive(Debug, Clone)]
+                pub struct Node<'n, $($ty: System),+> {
+                    accesser: &'n Accesser<$($ty),+>,
+                    emitter: &'n Emitter<$($ty),+>,
+                    callback_mode: &'n CallbackMode,
+                    transient: &'n super::Transient,
+                    items: ($(<$ty as super::State>::Node<'n>,)+),
+                }
+
+                impl<$($ty: System),+> super::State for ($($ty,)+) {
+                    const NODE_SIZE: super::IdSize = 1 $(+ <$ty as super::State>::NODE_SIZE)+;
+                    const NODE_ALT_SIZE: super::AltSize = 0;
+
+                    type Message = Message<$($ty),+>;
+                    type Emitter = Emitter<$($ty),+>;
+                    type Accesser = Accesser<$($ty),+>;
+                    type Node<'n> = Node<'n, $($ty),+>;
+
+                    fn from_payload(payload: &super::Payload) -> Self {
+                        super::Payload::to_state(payload)
+                    }
+
+                    fn to_payload(&self) -> super::Payload {
+                        super::Payload::from_state(self)
+                    }
+
+                    fn into_message(self) -> Self::Message {
+                        Message::State(self)
+                    }
+                }
+
+                impl<$($ty: System),+> super::Fallback for ($($ty,)+) {
+                    fn fallback(
+                        node: Node<'_, $($ty),+>,
+                        message: Message<$($ty),+>,
+                        delta: Option<std::time::Duration>,
+                    ) {
+                        match message {
+                            $(Message::$variant(message) => <$ty>::handle(node.items.$index, message, delta),)+
+                            Message::State(state) => {
+                                $(<$ty>::handle(node.items.$index, state.$index.into_message(), delta);)+
+                            },
+                        }
+                    }
+                }
+
+                impl<$($ty: System),+> super::System for ($($ty,)+) {
+
+                }
+
+                impl<$($ty: System),+> Message<$($ty),+> {
+                    const NODE_SIZES: &'static [super::IdSize] = &[$(<$ty as super::State>::NODE_SIZE),+];
+
+                    const fn id_delta(index: usize) -> super::IdDelta {
+                        super::id_delta(Self::NODE_SIZES, index)
+                    }
+                }
+
+                impl<$($ty: System),+> super::Message for Message<$($ty),+> {
+                    type State = ($($ty,)+);
+
+                    #[allow(clippy::needless_question_mark)]
+                    fn from_packet(
+                        packet: &super::Packet,
+                        parent_key: super::Key,
+                        depth: usize,
+                    ) -> super::Result<Self> {
+                        Ok(
+                            match packet.key().consist().id() - parent_key.consist().id() {
+                                0 => Ok(Self::State(
+                                    packet.decode(depth)?
+                                )),
+                                $(id_delta if (Self::id_delta($index)..Self::id_delta($index + 1)).contains(&id_delta) => Ok(
+                                    Message::$variant(<$ty as super::State>::Message::from_packet(
+                                        packet,
+                                        super::Key::new(
+                                            parent_key
+                                                .consist()
+                                                .access(
+                                                    Self::id_delta($index),
+                                                    <<Self as super::Message>::State as super::State>::NODE_ALT_SIZE,
+                                                ),
+                                            parent_key.transient(),
+                                        ),
+                                        depth + 1,
+                                    )?)
+                                ),)+
+                                id_delta => Err(super::PacketError::new(
+                                    packet.clone(),
+                                    Some(id_delta),
+                                    Some(depth),
+                                    format!("{}: unknown id_delta", std::any::type_name::<Self>()),
+                                )),
+                            }?,
+                        )
+                    }
+
+                    fn to_packet(&self, key: super::Key) -> super::Packet {
+                        match self {
+                            $(Self::$variant(message) => message.to_packet(key),)+
+                            Self::State(state) => super::Packet::new(key, super::State::to_payload(state)),
+                        }
+                    }
+
+                    fn apply_to(&self, state: &mut ($($ty,)+)) {
+                        match self {
+                            $(Self::$variant(message) => message.apply_to(&mut state.$index),)+
+                            Self::State(new_state) => *state = new_state.clone(),
+                        }
+                    }
+                }
+
+                impl<$($ty: System),+> super::Emitter<($($ty,)+)> for Emitter<$($ty),+> {
+                    fn callback(&self) -> &super::Callback<($($ty,)+)> {
+                        &self.callback
+                    }
+
+                    fn new(callback: super::Callback<($($ty,)+)>) -> Self {
+                        let node_sizes = Message::<$($ty),+>::NODE_SIZES;
+
+                        Self {
+                            items: ($(
+                                super::Emitter::new(super::Callback::access(
+                                    *callback.consist(),
+                                    callback.callback().clone(),
+                                    callback.process().clone(),
+                                    super::id_delta(node_sizes, $index),
+                                    |_, message| Message::$variant(message),
+                                )),
+                            )+),
+                            callback,
+                        }
+                    }
+                }
+
+                impl<$($ty: System),+> super::Accesser<($($ty,)+)> for Accesser<$($ty),+> {
+                    fn lookup(&self) -> &super::Lookup<($($ty,)+)> {
+                        &self.lookup
+                    }
+
+                    fn new<CS: System>(builder: super::LookupBuilder<CS, ($($ty,)+)>) -> Self {
+                        let node_sizes = Message::<$($ty),+>::NODE_SIZES;
+
+                        Self {
+                            items: ($(
+                                super::Accesser::new(builder.access(
+                                    |state, _| state.map(|state| &state.$index),
+                                    super::id_delta(node_sizes, $index),
+                                )),
+                            )+),
+                            lookup: builder.build(|state| state.cloned()),
+                        }
+                    }
+                }
+
+                impl<'n, $($ty: System),+> super::Node<'n, ($($ty,)+)> for Node<'n, $($ty),+> {
+                    fn accesser(&self) -> &Accesser<$($ty),+> { self.accesser }
+                    fn emitter(&self) -> &Emitter<$($ty),+> { self.emitter }
+                    fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+                    fn transient(&self) -> &super::Transient { self.transient }
+                }
+
+                impl<'n, $($ty: System),+> super::NewNode<'n, ($($ty,)+)> for Node<'n, $($ty),+> {
+                    fn new(
+                        accesser: &'n Accesser<$($ty),+>,
+                        emitter: &'n Emitter<$($ty),+>,
+                        callback_mode: &'n CallbackMode,
+                        transient: &'n super::Transient,
+                    ) -> Self {
+                        Self {
+                            accesser,
+                            emitter,
+                            callback_mode,
+                            transient,
+                            items: ($(
+                                super::NewNode::new(
+                                    &accesser.items.$index,
+                                    &emitter.items.$index,
+                                    callback_mode,
+                                    transient,
+                                ),
+                            )+),
+                        }
+                    }
+                }
+
+                impl<'n, $($ty: System),+> Deref for Node<'n, $($ty),+> {
+                    type Target = ($(<$ty as super::State>::Node<'n>,)+);
+                    fn deref(&self) -> &Self::Target { &self.items }
+                }
+            }
+        )+
+    };
+}
+
+impl_tuple_node! {
+    tuple2 { 0 A Item0, 1 B Item1 }
+    tuple3 { 0 A Item0, 1 B Item1, 2 C Item2 }
+    tuple4 { 0 A Item0, 1 B Item1, 2 C Item2, 3 D Item3 }
+    tuple5 { 0 A Item0, 1 B Item1, 2 C Item2, 3 D Item3, 4 E Item4 }
+    tuple6 { 0 A Item0, 1 B Item1, 2 C Item2, 3 D Item3, 4 E Item4, 5 F Item5 }
+    tuple7 { 0 A Item0, 1 B Item1, 2 C Item2, 3 D Item3, 4 E Item4, 5 F Item5, 6 G Item6 }
+    tuple8 { 0 A Item0, 1 B Item1, 2 C Item2, 3 D Item3, 4 E Item4, 5 F Item5, 6 G Item6, 7 H Item7 }
+}
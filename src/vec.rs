@@ -1,6 +1,7 @@
 use std::vec::IntoIter;
 use crate::ext::*;
 
+#[allow(clippy::module_inception)]
 pub mod vec {
     use super::*;
 
@@ -47,7 +48,7 @@ pub mod vec {
     }
 
     impl<I: System> super::State for Vec<I> {
-        const NODE_SIZE: super::IdSize = 1 + <super::AltIndex as super::State>::NODE_SIZE + <I as super::State>::NODE_SIZE;
+        const NODE_SIZE: super::IdSize = ITEM_ID_DELTA + <I as super::State>::NODE_SIZE;
         const NODE_ALT_SIZE: super::AltSize = 1;
     
         type Message = vec::Message<I>;
@@ -97,6 +98,7 @@ pub mod vec {
     impl<I: System> super::Message for Message<I> {
         type State = Vec<I>;
 
+        #[allow(clippy::needless_question_mark)]
         fn from_packet(
             packet: &super::Packet,
             parent_key: super::Key,
@@ -105,10 +107,10 @@ pub mod vec {
             Ok(
                 match packet.key().consist().id() - parent_key.consist().id() {
                     0 => Ok(Self::State(
-                        super::State::from_payload(packet.payload())
+                        packet.decode(depth)?
                     )),
                     PUSH_ID_DELTA..PUSH_ID_DELTA_END => Ok(Message::Push(
-                        super::State::from_payload(packet.payload())
+                        packet.decode(depth)?
                     )),
                     POP_ID_DELTA..POP_ID_DELTA_END => Ok(Message::Pop),
                     LEN_ID_DELTA..LEN_ID_DELTA_END => Ok(Message::Len(
@@ -219,7 +221,7 @@ pub mod vec {
         fn new<CS: System>(builder: LookupBuilder<CS, Vec<I>>) -> Self {
             Self { 
                 item: super::Accesser::new(builder.access(
-                    |state, index| state.map(|state| state.get(index as usize)).flatten(), 
+                    |state, index| state.and_then(|state| state.get(index as usize)), 
                     ITEM_ID_DELTA
                 )),
                 lookup: builder.clone().build(|state| state.cloned()), 
@@ -231,8 +233,8 @@ pub mod vec {
     impl<'n, I: System> super::Node<'n, Vec<I>> for Node<'n, I> {
         fn accesser(&self) -> &Accesser<I> { self.accesser }
         fn emitter(&self) -> &Emitter<I> { self.emitter }
-        fn callback_mode(&self) -> &CallbackMode { &self.callback_mode }
-        fn transient(&self) -> &super::Transient { &self.transient }
+        fn callback_mode(&self) -> &CallbackMode { self.callback_mode }
+        fn transient(&self) -> &super::Transient { self.transient }
     }
     
     impl<'n, I: System> super::NewNode<'n, Vec<I>> for Node<'n, I> {
@@ -259,11 +261,19 @@ pub mod vec {
 
     impl<'n, I: System> Node<'n, I> {
         pub fn emit_push(&self, item: I) {
-            self.emitter.push.emit(self.callback_mode, self.transient, item.into_message());
+            EmitError::report(self.try_emit_push(item));
+        }
+
+        pub fn try_emit_push(&self, item: I) -> Result<(), EmitError> {
+            self.emitter.push.try_emit(self.callback_mode, self.transient, item.into_message())
         }
 
         pub fn emit_pop(&self) {
-            self.emitter.pop.emit(self.callback_mode, self.transient, ());            
+            EmitError::report(self.try_emit_pop());
+        }
+
+        pub fn try_emit_pop(&self) -> Result<(), EmitError> {
+            self.emitter.pop.try_emit(self.callback_mode, self.transient, ())
         }
 
         pub fn items(&self) -> IntoIter<NodeAlt<'_, I>> {
@@ -280,6 +290,10 @@ pub mod vec {
             self.accesser.lookup_len.get(self.transient).unwrap_or_default()
         }
 
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
         pub fn item(&self, index: AltIndex) -> NodeAlt<'_, I> {
             use crate::ext::Node;
             self.item.alt(self.consist(), index)
@@ -0,0 +1,35 @@
+#![cfg(feature = "num-bigint")]
+
+use frand_node::ext::*;
+use num_bigint::{BigInt, BigUint};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Balances {
+    pub signed: BigInt,
+    pub unsigned: BigUint,
+}
+
+impl System for Balances {}
+
+#[test]
+fn bigint_terminals_round_trip() {
+    let signed: BigInt = "-123456789012345678901234567890123456789".parse().unwrap();
+    let unsigned: BigUint = "340282366920938463463374607431768211457".parse().unwrap();
+    let mut component = Component::new(Balances::default());
+
+    component.node().signed.emit(signed.clone());
+    component.node().unsigned.emit(unsigned.clone());
+    let output = component.try_update();
+
+    let mut state = Balances::default();
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        balances::Message::from_packet(&packet, Key::default(), 0).unwrap().apply_to(&mut state);
+    }
+
+    assert_eq!(state, component.node().clone_state().unwrap());
+    assert_eq!(state.signed, signed);
+    assert_eq!(state.unsigned, unsigned);
+}
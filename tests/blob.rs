@@ -0,0 +1,51 @@
+use frand_node::ext::*;
+
+#[test]
+fn patch_rejects_overflow_and_max_len() {
+    let mut blob = Blob::new(vec![1, 2, 3]).with_max_len(8);
+
+    assert!(!blob.patch(usize::MAX, &[4, 5]));
+    assert!(!blob.patch(7, &[4, 5]));
+    assert!(!blob.replace(&[0; 9]));
+    assert_eq!(blob.bytes(), &[1, 2, 3]);
+
+    assert!(blob.patch(6, &[7, 8]));
+    assert_eq!(blob.bytes(), &[1, 2, 3, 0, 0, 0, 7, 8]);
+}
+
+#[test]
+fn rejected_patch_leaves_state() {
+    let mut component = Component::new(Blob::new(vec![1, 2, 3]).with_max_len(4));
+
+    assert!(component.node().try_emit_patch(usize::MAX - 1, &[9, 9]).is_err());
+    assert!(component.node().try_emit_patch(2, &[9, 9, 9]).is_err());
+    component.try_update();
+
+    assert_eq!(component.node().read(0..3), Some(vec![1, 2, 3]));
+
+    blob::Message::Patch { offset: usize::MAX, bytes: vec![9] }
+        .apply_to(&mut Blob::new(vec![1]));
+}
+
+#[test]
+fn oversized_replace_is_rejected_before_any_chunk() {
+    let mut component = Component::new(
+        Blob::new(vec![1, 2, 3]).with_chunk_size(2).with_max_len(4),
+    );
+
+    assert_eq!(
+        component.node().try_emit_replace(&[9; 5]),
+        Err(EmitError::TooLarge { len: 5, max_len: 4 }),
+    );
+    assert_eq!(
+        component.node().try_emit_patch(3, &[9; 2]),
+        Err(EmitError::TooLarge { len: 5, max_len: 4 }),
+    );
+    assert!(component.try_update().is_empty());
+    assert_eq!(component.node().read(0..3), Some(vec![1, 2, 3]));
+
+    component.node().emit_replace(&[5, 6, 7, 8]);
+    component.try_update();
+
+    assert_eq!(component.node().read(0..4), Some(vec![5, 6, 7, 8]));
+}
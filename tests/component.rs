@@ -0,0 +1,174 @@
+use frand_node::{bases::component::MAX_CASCADE_ERRORS, ext::*};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub items: Vec<u32>,
+    pub value: u32,
+}
+
+impl System for Doc {}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Clamp {
+    pub value: u32,
+}
+
+impl System for Clamp {
+    fn handle(
+        node: Self::Node<'_>,
+        message: Self::Message,
+        delta: Option<std::time::Duration>,
+    ) {
+        use clamp::Message::*;
+
+        match message {
+            // 자신의 Key 로 다시 emit 하므로 연쇄 안에서 순환으로 기록됨
+            Value(value) => node.value.emit(value.min(10)),
+            message => Self::fallback(node, message, delta),
+        }
+    }
+}
+
+// value 의 handler 가 받은 메시지를 seen 에 남김
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Tally {
+    pub items: Vec<u32>,
+    pub value: u32,
+    pub seen: Vec<u32>,
+}
+
+impl System for Tally {
+    fn handle(
+        node: Self::Node<'_>,
+        message: Self::Message,
+        delta: Option<std::time::Duration>,
+    ) {
+        use tally::Message::*;
+
+        match message {
+            Value(value) => node.seen.emit_push(value),
+            message => Self::fallback(node, message, delta),
+        }
+    }
+}
+
+#[tokio::test]
+async fn block_overflow_does_not_park_tokio() {
+    let mut component = Component::bounded(Doc::default(), 1, Overflow::Block);
+
+    assert_eq!(component.node().value.try_emit(1), Ok(()));
+    // tokio 안에서 큐가 가득 차면 멈추지 않고 오류를 반환해야 함
+    assert_eq!(component.node().value.try_emit(2), Err(EmitError::WouldBlock));
+    assert_eq!(component.node().items.try_emit_push(3), Err(EmitError::WouldBlock));
+
+    component.try_update();
+    assert_eq!(component.node().value.v(), 1);
+    assert_eq!(component.node().items.try_emit_push(3), Ok(()));
+}
+
+#[tokio::test]
+async fn emit_async_waits_for_space() {
+    let handle = Component::bounded(Doc::default(), 1, Overflow::Block).into_handle();
+
+    for item in 0..4 {
+        // 큐가 가득 차면 update task 가 비울 때까지 기다렸다가 다시 emit 함
+        handle.emit_async(|node| node.items.try_emit_push(item)).await.unwrap();
+    }
+
+    handle.shutdown().await;
+
+    assert_eq!(handle.node().items.clone_state().unwrap(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn block_overflow_waits_outside_tokio() {
+    let mut component = Component::bounded(Doc::default(), 1, Overflow::Block);
+    let consensus = component.consensus().clone();
+
+    let emitter = std::thread::spawn(move || {
+        for item in 0..4 {
+            consensus.node().items.emit_push(item);
+        }
+    });
+
+    while component.node().items.len() < 4 {
+        component.try_update();
+        std::thread::yield_now();
+    }
+
+    emitter.join().unwrap();
+    assert_eq!(component.node().items.clone_state().unwrap(), vec![0, 1, 2, 3]);
+}
+
+// 결과를 돌려주지 않는 emit 도 입력이 버려지면 debug 빌드에서 알려야 함
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "emit dropped a message")]
+fn emit_reports_error_overflow() {
+    let component = Component::bounded(Doc::default(), 1, Overflow::Error);
+
+    component.node().value.emit(1);
+    component.node().value.emit(2);
+}
+
+#[cfg(debug_assertions)]
+#[tokio::test]
+#[should_panic(expected = "emit dropped a message")]
+async fn emit_reports_block_overflow_inside_tokio() {
+    let component = Component::bounded(Doc::default(), 1, Overflow::Block);
+
+    component.node().items.emit_push(1);
+    component.node().items.emit_push(2);
+}
+
+#[test]
+fn try_emit_helpers_report_closed() {
+    let component = Component::new(Doc::default());
+    let consensus = component.consensus().clone();
+    drop(component);
+
+    assert_eq!(consensus.node().value.try_emit(1), Err(EmitError::Closed));
+    assert_eq!(consensus.node().items.try_emit_push(1), Err(EmitError::Closed));
+    assert_eq!(consensus.node().value.try_emit_carry(|| 1u32.into_message()), Err(EmitError::Closed));
+}
+
+#[test]
+fn cascade_errors_are_capped() {
+    let mut component = Component::new(Clamp::default());
+    let key = component.node().value.key();
+
+    for value in 0..MAX_CASCADE_ERRORS as u32 + 10 {
+        component.node().value.emit(value);
+    }
+    component.try_update();
+
+    let errors = component.take_errors();
+    assert_eq!(errors.len(), MAX_CASCADE_ERRORS);
+    assert_eq!(errors.last(), Some(&CascadeError::Cycle { chain: vec![key, key] }));
+    assert!(component.take_errors().is_empty());
+}
+
+#[test]
+fn transaction_applies_every_message() {
+    let mut component = Component::new(Tally::default());
+
+    component.transaction(|node| {
+        node.items.emit_push(1);
+        node.items.emit_push(2);
+        node.value.emit(3);
+        node.value.emit(4);
+    }).unwrap();
+
+    let output = component.try_update();
+
+    assert_eq!(component.node().items.clone_state(), Some(vec![1, 2]));
+    assert_eq!(component.node().value.v(), 4);
+    // handler 는 모든 메시지에 대해 순서대로 호출됨
+    assert_eq!(component.node().seen.clone_state(), Some(vec![3, 4]));
+
+    // 묶음의 메시지 4개와 handler 가 이어서 emit 한 메시지 2개가 같은 batch 로 표시됨
+    assert_eq!(output.len(), 6);
+    assert!(output[0].batch.is_some());
+    assert!(output.iter().all(|packet| packet.batch == output[0].batch));
+}
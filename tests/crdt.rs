@@ -0,0 +1,60 @@
+use frand_node::ext::*;
+use frand_node::{counter::Count, register::Hlc};
+
+#[test]
+fn set_uses_component_replica_and_clock() {
+    let mut component = Component::new(Register::<u32>::default())
+        .with_replica(7)
+        .with_clock(|| 1000);
+
+    component.node().emit_set(1);
+    component.node().emit_set(2);
+    component.try_update();
+
+    assert_eq!(component.node().value(), 2);
+    assert_eq!(component.node().stamp(), Hlc { wall: 1000, logical: 1, replica: 7 });
+}
+
+#[test]
+fn from_value_wins_over_previous_set() {
+    let mut component = Component::new(Register::<u32>::default());
+
+    component.node().emit_set(1);
+    component.try_update();
+
+    // 같은 밀리초의 stamp 는 replica 로 순서가 정해지므로 시각을 넘김
+    std::thread::sleep(std::time::Duration::from_millis(2));
+
+    component.node().emit(Register::from(2));
+    component.try_update();
+
+    assert_eq!(component.node().value(), 2);
+}
+
+#[test]
+fn counter_value_saturates() {
+    let mut counter = Counter::default();
+
+    counter.apply_increment(&Count { replica: 1, total: u64::MAX });
+    counter.apply_increment(&Count { replica: 2, total: u64::MAX });
+    assert_eq!(counter.value(), i64::MAX);
+
+    counter.apply_decrement(&Count { replica: 3, total: u64::MAX });
+    counter.apply_decrement(&Count { replica: 4, total: u64::MAX });
+    counter.apply_decrement(&Count { replica: 5, total: u64::MAX });
+    assert_eq!(counter.value(), i64::MIN);
+}
+
+#[test]
+fn counter_uses_component_replica() {
+    let mut component = Component::new(Counter::default()).with_replica(9);
+
+    component.node().emit_increment(3);
+    component.node().emit_increment(2);
+    component.try_update();
+
+    let counter = component.node().clone_state().unwrap();
+
+    assert_eq!(counter.value(), 5);
+    assert_eq!(counter.prepare_increment(9, 1).total, 6);
+}
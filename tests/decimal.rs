@@ -0,0 +1,36 @@
+#![cfg(feature = "rust_decimal")]
+
+use frand_node::ext::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Prices {
+    pub price: Decimal,
+    pub fee: Decimal,
+}
+
+impl System for Prices {}
+
+#[test]
+fn decimal_terminals_round_trip() {
+    let mut component = Component::new(Prices::default());
+
+    // 이진 부동소수점으로는 표현되지 않는 값도 scale 까지 그대로 전달되어야 함
+    component.node().price.emit(Decimal::new(1999, 2));
+    component.node().fee.emit(Decimal::new(-1, 28));
+    let output = component.try_update();
+
+    let mut state = Prices::default();
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        prices::Message::from_packet(&packet, Key::default(), 0).unwrap().apply_to(&mut state);
+    }
+
+    assert_eq!(state, component.node().clone_state().unwrap());
+    assert_eq!(state.price, Decimal::new(1999, 2));
+    assert_eq!(state.price.scale(), 2);
+    assert_eq!(state.fee, Decimal::new(-1, 28));
+    assert_eq!(component.node().price.v(), Decimal::new(1999, 2));
+}
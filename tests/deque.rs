@@ -0,0 +1,103 @@
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Node)]
+pub struct Recent {
+    pub items: Deque<u32>,
+    pub after: u32,
+}
+
+impl System for Recent {}
+
+fn recent() -> Recent {
+    Recent {
+        items: Deque::bounded(3),
+        after: 0,
+    }
+}
+
+fn values(node: &deque::Node<'_, u32>) -> Vec<(AltIndex, u32)> {
+    node.items()
+        .enumerate()
+        .map(|(position, item)| (node.front_index().wrapping_add(position as AltIndex), item.v()))
+        .collect()
+}
+
+#[test]
+fn push_past_capacity_evicts_oldest_with_stable_indices() {
+    let mut component = Component::new(recent());
+
+    for value in 1..=5 {
+        component.node().items.emit_push_back(value);
+    }
+    component.try_update();
+
+    // 용량을 넘으면 가장 오래된 항목부터 제거되고 남은 항목의 index 는 유지됨
+    assert_eq!(component.node().items.front_index(), 2);
+    assert_eq!(values(&component.node().items), vec![(2, 3), (3, 4), (4, 5)]);
+
+    component.node().items.item(3).emit(40);
+    component.try_update();
+
+    assert_eq!(values(&component.node().items), vec![(2, 3), (3, 40), (4, 5)]);
+
+    component.node().items.emit_push_front(0);
+    component.try_update();
+
+    // 앞에 넣으면 뒤쪽 항목이 제거됨
+    assert_eq!(values(&component.node().items), vec![(1, 0), (2, 3), (3, 40)]);
+}
+
+#[test]
+fn pop_front_and_back_keep_indices() {
+    let mut component = Component::new(recent());
+
+    for value in 1..=3 {
+        component.node().items.emit_push_back(value);
+    }
+    component.try_update();
+
+    component.node().items.emit_pop_front();
+    component.try_update();
+
+    assert_eq!(component.node().items.front_index(), 1);
+    assert_eq!(values(&component.node().items), vec![(1, 2), (2, 3)]);
+
+    component.node().items.emit_pop_back();
+    component.try_update();
+
+    assert_eq!(values(&component.node().items), vec![(1, 2)]);
+
+    component.node().items.emit_pop_back();
+    component.node().items.emit_pop_front();
+    component.try_update();
+
+    assert!(component.node().items.is_empty());
+}
+
+#[test]
+fn packets_round_trip_after_eviction() {
+    let mut from = Component::new(recent());
+
+    for value in 1..=5 {
+        from.node().items.emit_push_back(value);
+    }
+    from.node().items.item(4).emit(50);
+    from.node().items.emit_pop_front();
+    from.node().after.emit(7);
+    let output = from.try_update();
+
+    let mut to = recent();
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        let message = recent::Message::from_packet(&packet, Key::default(), 0).unwrap();
+        message.apply_to(&mut to);
+    }
+
+    assert_eq!(to.items.front_index(), 3);
+    assert_eq!(to.items.indices().collect::<Vec<_>>(), vec![3, 4]);
+    assert_eq!(to.items.get(3), Some(&4));
+    assert_eq!(to.items.get(4), Some(&50));
+    assert_eq!(to.after, 7);
+}
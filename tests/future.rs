@@ -0,0 +1,42 @@
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub items: Vec<u32>,
+    pub value: u32,
+}
+
+impl System for Doc {}
+
+#[test]
+fn finished_latest_futures_are_pruned() {
+    let mut component = Component::new(Doc { items: vec![0; 3], value: 0 });
+
+    for index in 0..3 {
+        let handle = component.node().items.item(index).emit_future_latest(async move { index + 1 });
+        assert!(!handle.is_finished());
+    }
+    component.try_update();
+    assert_eq!(component.latest_len(), 3);
+
+    component.try_update();
+    assert_eq!(component.node().items.clone_state(), Some(vec![1, 2, 3]));
+    assert_eq!(component.latest_len(), 0);
+}
+
+#[test]
+fn cancelled_latest_futures_are_pruned() {
+    let mut component = Component::new(Doc::default());
+
+    let handle = component.node().value.emit_future_latest(std::future::pending());
+    component.try_update();
+    assert_eq!(component.latest_len(), 1);
+
+    handle.cancel();
+    assert!(handle.is_finished());
+
+    component.try_update();
+    assert_eq!(component.latest_len(), 0);
+    assert_eq!(component.node().value.v(), 0);
+}
@@ -0,0 +1,19 @@
+use frand_node::ext::*;
+use frand_node::grid::MAX_GRID_LEN;
+
+#[test]
+fn resize_clamps_to_grid_index() {
+    let mut component = Component::new(Grid::<u32>::new(u32::MAX, 2));
+
+    assert_eq!(component.node().rows(), MAX_GRID_LEN);
+
+    component.node().emit_resize_cols(u32::MAX);
+    component.try_update();
+
+    assert_eq!(component.node().cols(), MAX_GRID_LEN);
+
+    component.node().cell(u16::MAX, u16::MAX).emit(7);
+    component.try_update();
+
+    assert_eq!(component.node().cell(u16::MAX, u16::MAX).v(), 7);
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+use futures::StreamExt;
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub value: u32,
+}
+
+impl System for Doc {}
+
+fn values(output: Vec<frand_node::bases::packet::MessagePacketMessage<Doc>>) -> Vec<u32> {
+    output.into_iter()
+        .filter_map(|packet| match packet.message {
+            doc::Message::Value(value) => Some(value),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn shutdown_drains_into_output() {
+    let handle = Component::spawn(Doc::default());
+    let output = handle.output();
+
+    handle.node().value.emit(1);
+    handle.node().value.emit_future(async {
+        tokio::task::yield_now().await;
+        2
+    });
+    handle.shutdown().await;
+
+    assert!(handle.is_finished());
+    assert_eq!(handle.node().value.v(), 2);
+    assert_eq!(values(output.collect().await), vec![1, 2]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn shutdown_flushes_timed_carries() {
+    let handle = Component::spawn(Doc::default());
+    let output = handle.output();
+    let start = Instant::now();
+
+    handle.node().value.emit_debounced(5, Duration::from_secs(10));
+    handle.shutdown().await;
+
+    // debounce 의 시점을 기다리지 않고 적용됨
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert_eq!(handle.node().value.v(), 5);
+    assert_eq!(values(output.collect().await), vec![5]);
+}
+
+#[tokio::test]
+async fn shutdown_timeout_aborts_pending_future() {
+    let handle = Component::spawn(Doc::default());
+
+    handle.node().value.emit_future(std::future::pending());
+    handle.node().value.emit(1);
+
+    assert!(!handle.shutdown_timeout(Duration::from_millis(10)).await);
+    assert!(handle.is_finished());
+    assert_eq!(handle.node().value.v(), 1);
+}
@@ -0,0 +1,56 @@
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub value: u32,
+    pub log: Vec<u32>,
+}
+
+impl System for Doc {}
+
+fn component() -> Component<Doc> {
+    let component = Component::new(Doc::default());
+    let mut history = History::new(Grouping::Input);
+    history.exclude(&component.node().log);
+    component.with_history(history)
+}
+
+#[test]
+fn excluded_push_is_not_applied_twice() {
+    let mut component = component();
+
+    component.node().value.emit(1);
+    component.node().log.emit_push(5);
+    component.try_update();
+
+    component.node().value.emit(2);
+    component.try_update();
+
+    component.undo().unwrap();
+    assert_eq!(component.node().value.v(), 1);
+    // 제외된 노드는 되돌리지 않고 현재 상태를 유지해야 함
+    assert_eq!(component.node().log.clone_state(), Some(vec![5]));
+
+    component.redo().unwrap();
+    assert_eq!(component.node().value.v(), 2);
+    assert_eq!(component.node().log.clone_state(), Some(vec![5]));
+}
+
+#[test]
+fn clear_forgets_excluded_keys() {
+    let mut component = component();
+
+    component.node().log.emit_push(5);
+    component.try_update();
+    component.history_mut().unwrap().clear();
+
+    component.node().value.emit(1);
+    component.try_update();
+
+    // clear 이전에 기록된 제외 Key 는 restore 에 포함되지 않음
+    let output = component.undo().unwrap();
+    assert_eq!(output.len(), 1);
+    assert_eq!(component.node().value.v(), 0);
+    assert_eq!(component.node().log.clone_state(), Some(vec![5]));
+}
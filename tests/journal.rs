@@ -0,0 +1,133 @@
+use std::{fs, io, path::PathBuf};
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub items: Vec<u32>,
+    pub value: u32,
+}
+
+impl System for Doc {}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("frand-node-journal-{}-{name}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    dir
+}
+
+fn frame(sequence: u64, packet: &Packet) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    ciborium::into_writer(packet, &mut buffer).unwrap();
+
+    let mut frame = (buffer.len() as u32).to_le_bytes().to_vec();
+    frame.extend(sequence.to_le_bytes());
+    frame.extend(buffer);
+    frame
+}
+
+fn push(component: &mut Component<Doc>, items: impl IntoIterator<Item = u32>) {
+    for item in items {
+        component.node().items.emit_push(item);
+    }
+    component.try_update();
+    assert!(component.take_journal_errors().is_empty());
+}
+
+#[test]
+fn write_then_recover() {
+    let dir = temp_dir("write");
+    let policy = JournalPolicy { fsync: Fsync::Always, snapshot_every: None };
+
+    let mut component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    push(&mut component, [1, 2]);
+    component.node().value.emit(7);
+    component.try_update();
+    drop(component);
+
+    let component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    assert_eq!(component.node().clone_state(), Some(Doc { items: vec![1, 2], value: 7 }));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn compact_then_recover() {
+    let dir = temp_dir("compact");
+    let policy = JournalPolicy { fsync: Fsync::Never, snapshot_every: None };
+
+    let mut component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    push(&mut component, [1, 2]);
+    drop(component);
+
+    // 복구하면서 snapshot 을 쓰고 journal 을 비우는데, 비우기 전에 중단된 것처럼 journal 을 되돌림
+    let journal = fs::read(dir.join("journal.log")).unwrap();
+    let component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    assert_eq!(component.journal().unwrap().entries(), 0);
+    drop(component);
+    fs::write(dir.join("journal.log"), journal).unwrap();
+
+    let mut component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    assert_eq!(component.node().items.clone_state(), Some(vec![1, 2]));
+
+    push(&mut component, [3]);
+    drop(component);
+
+    let component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    assert_eq!(component.node().items.clone_state(), Some(vec![1, 2, 3]));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn torn_tail_is_dropped() {
+    let dir = temp_dir("torn");
+    let policy = JournalPolicy { fsync: Fsync::Always, snapshot_every: None };
+
+    let mut component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    push(&mut component, [1]);
+    let journal = fs::read(dir.join("journal.log")).unwrap();
+    push(&mut component, [2]);
+    drop(component);
+
+    // 마지막 기록이 절반만 쓰인 journal
+    let mut torn = journal.clone();
+    torn.extend(&journal[..journal.len() / 2]);
+    fs::write(dir.join("journal.log"), torn).unwrap();
+
+    let mut component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    assert_eq!(component.node().items.clone_state(), Some(vec![1]));
+
+    push(&mut component, [4]);
+    drop(component);
+
+    let component = Component::<Doc>::recover_with(&dir, policy).unwrap();
+    assert_eq!(component.node().items.clone_state(), Some(vec![1, 4]));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn corrupt_entry_is_invalid_data() {
+    let dir = temp_dir("corrupt");
+    fs::create_dir_all(&dir).unwrap();
+
+    // Key 는 맞지만 payload 가 u32 가 아닌 기록
+    let key = Component::new(Doc::default()).node().value.key();
+    let packet = Packet::new(key, Payload::from_value(&"not a number"));
+    fs::write(dir.join("journal.log"), frame(1, &packet)).unwrap();
+
+    let err = Component::<Doc>::recover(&dir).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // CBOR 로 읽을 수 없는 기록
+    let mut garbage = 4u32.to_le_bytes().to_vec();
+    garbage.extend(1u64.to_le_bytes());
+    garbage.extend([0xff; 4]);
+    fs::write(dir.join("journal.log"), garbage).unwrap();
+
+    let err = Component::<Doc>::recover(&dir).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Inner {
+    pub value: u32,
+    pub items: Vec<u32>,
+}
+
+impl System for Inner {}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Pointers {
+    pub boxed: Box<Inner>,
+    pub shared: Arc<Inner>,
+    pub after: u32,
+}
+
+impl System for Pointers {}
+
+#[test]
+fn pointer_nodes_forward_to_inner() {
+    let mut component = Component::new(Pointers::default());
+
+    component.node().boxed.value.emit(1);
+    component.node().boxed.items.emit_push(2);
+    component.node().shared.value.emit(3);
+    component.node().shared.items.emit_push(4);
+    component.node().after.emit(5);
+    component.try_update();
+
+    assert_eq!(component.node().boxed.value.v(), 1);
+    assert_eq!(component.node().boxed.items.len(), 1);
+    assert_eq!(component.node().shared.value.v(), 3);
+    assert_eq!(component.node().shared.items.len(), 1);
+    assert_eq!(component.node().after.v(), 5);
+}
+
+#[test]
+fn pointer_state_emits_unwrap_shared_arcs() {
+    let mut component = Component::new(Pointers::default());
+
+    let shared = Arc::new(Inner { value: 6, items: vec![7] });
+    component.node().boxed.emit(Box::new(Inner { value: 8, items: vec![9] }));
+    // 다른 곳에서 잡고 있는 Arc 도 복제해서 메시지로 만들어야 함
+    component.node().shared.emit(shared.clone());
+    component.try_update();
+
+    assert_eq!(*component.node().boxed.clone_state().unwrap(), Inner { value: 8, items: vec![9] });
+    assert_eq!(component.node().shared.clone_state().unwrap(), shared);
+}
+
+#[test]
+fn pointer_packets_apply_to_state() {
+    let mut from = Component::new(Pointers::default());
+
+    from.node().boxed.value.emit(1);
+    from.node().shared.value.emit(2);
+    from.node().shared.items.emit_push(3);
+    from.node().after.emit(4);
+    let output = from.try_update();
+
+    let mut to = Pointers::default();
+    let held = to.shared.clone();
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        let message = pointers::Message::from_packet(&packet, Key::default(), 0).unwrap();
+        message.apply_to(&mut to);
+    }
+
+    assert_eq!(to, from.node().clone_state().unwrap());
+    // 공유된 Arc 는 make_mut 로 복제된 뒤 수정되어야 함
+    assert_eq!(*held, Inner::default());
+    assert_eq!(*to.shared, Inner { value: 2, items: vec![3] });
+}
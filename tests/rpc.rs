@@ -0,0 +1,93 @@
+use frand_node::ext::*;
+use frand_node::rpc::RequestId;
+use serde::{Deserialize, Serialize};
+
+// respond 에 emit 된 (replica, sequence, value) 를 한 번에 응답
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Node)]
+pub struct Service {
+    pub rpc: Rpc<u32, u32>,
+    pub respond: Vec<(u64, u32, u32)>,
+}
+
+// Service 와 같은 구조로 요청만 보냄
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Node)]
+pub struct Client {
+    pub rpc: Rpc<u32, u32>,
+    pub respond: Vec<(u64, u32, u32)>,
+}
+
+impl System for Client {}
+
+impl System for Service {
+    fn handle(
+        node: Self::Node<'_>,
+        message: Self::Message,
+        delta: Option<std::time::Duration>,
+    ) {
+        use service::Message::*;
+
+        match message {
+            Respond(vec::Message::State(responses)) => {
+                for (replica, sequence, value) in responses {
+                    node.rpc.emit_response(RequestId { replica, sequence }, value);
+                }
+            },
+            message => Self::fallback(node, message, delta),
+        }
+    }
+}
+
+fn inject<S: System>(to: &Component<S>, packets: impl IntoIterator<Item = Packet>) {
+    for packet in packets {
+        let message = S::Message::from_packet(&packet, Key::default(), 0).unwrap();
+        (to.node().emitter().callback().callback())(MessagePacket::message(packet.key(), message)).unwrap();
+    }
+}
+
+fn packets<S: System>(component: &mut Component<S>) -> Vec<Packet> {
+    component.try_update().into_iter()
+        .map(|packet| packet.message.to_packet(packet.key))
+        .collect()
+}
+
+#[test]
+fn responses_to_two_replicas_in_one_cascade() {
+    let mut server = Component::new(Service::default());
+    let mut a = Component::new(Client::default()).with_replica(1);
+    let mut b = Component::new(Client::default()).with_replica(2);
+
+    let mut a_rx = a.node().rpc.emit_request(10);
+    let mut b_rx = b.node().rpc.emit_request(20);
+    a.try_update();
+    b.try_update();
+
+    // 두 replica 의 요청은 같은 sequence 를 가짐
+    server.node().respond.emit(vec![(1, 0, 20), (2, 0, 40)]);
+    let responses = packets(&mut server);
+
+    inject(&a, responses.clone());
+    inject(&b, responses);
+    a.try_update();
+    b.try_update();
+
+    assert_eq!(a_rx.try_recv(), Ok(20));
+    assert_eq!(b_rx.try_recv(), Ok(40));
+    assert_eq!(a.node().rpc.pending_len(), 0);
+    assert_eq!(b.node().rpc.pending_len(), 0);
+}
+
+#[test]
+fn dropped_receivers_are_pruned() {
+    let mut client = Component::new(Client::default());
+
+    drop(client.node().rpc.emit_request(1));
+    let _rx = client.node().rpc.emit_request(2);
+    client.try_update();
+
+    assert_eq!(client.node().rpc.pending_len(), 1);
+
+    // 복제된 상태는 대기 중인 요청을 공유하지 않음
+    let state = client.node().clone_state().unwrap();
+    assert_eq!(state.rpc.pending_len(), 0);
+    assert_eq!(client.node().rpc.pending_len(), 1);
+}
@@ -0,0 +1,72 @@
+use std::sync::{atomic::{AtomicU64, Ordering}, Arc};
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+// input 을 받으면 replica id 와 시계를 쓰는 CRDT 메시지를 emit
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Node)]
+pub struct Ledger {
+    pub input: u32,
+    pub stamp: Register<u32>,
+    pub count: Counter,
+}
+
+impl System for Ledger {
+    fn handle(
+        node: Self::Node<'_>,
+        message: Self::Message,
+        delta: Option<std::time::Duration>,
+    ) {
+        use ledger::Message::*;
+
+        match message {
+            Input(value) => {
+                node.stamp.emit_set(value);
+                node.count.emit_increment(1);
+            },
+            message => Self::fallback(node, message, delta),
+        }
+    }
+}
+
+#[test]
+fn replay_reproduces_recorded_output() {
+    let clock = Arc::new(AtomicU64::new(1000));
+
+    let component = Component::new(Ledger::default())
+        .with_replica(3)
+        .with_clock({
+            let clock = clock.clone();
+            move || clock.fetch_add(10, Ordering::Relaxed)
+        });
+
+    let mut recorder = Recorder::new(component);
+
+    for value in 1..=3 {
+        recorder.node().input.emit(value);
+        recorder.try_update();
+    }
+
+    recorder.transaction(|node| {
+        node.input.emit(4);
+        node.input.emit(5);
+    }).unwrap();
+    recorder.try_update();
+
+    let (component, session) = recorder.into_inner();
+    assert_eq!(session.replica, 3);
+    assert_eq!(session.ticks.len(), 4);
+
+    // 새 Component 의 replica id 와 시계 대신 기록된 값으로 같은 Output 이 나와야 함
+    let path = std::env::temp_dir().join(format!("frand-node-session-{}.cbor", std::process::id()));
+    session.save(&path).unwrap();
+    let replayed = Replayer::<Ledger>::load(&path).unwrap().replay().unwrap();
+    std::fs::remove_file(&path).ok();
+
+    let expected = component.node().clone_state().unwrap();
+    let actual = replayed.node().clone_state().unwrap();
+
+    assert_eq!(actual.stamp.stamp(), expected.stamp.stamp());
+    assert_eq!(*actual.stamp.value(), 5);
+    assert_eq!(actual.count.value(), expected.count.value());
+    assert_eq!(actual.count.value(), 5);
+}
@@ -0,0 +1,96 @@
+use std::collections::{BTreeSet, HashSet};
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Tags {
+    pub ordered: BTreeSet<u32>,
+    pub hashed: HashSet<u32>,
+    pub after: u32,
+}
+
+impl System for Tags {}
+
+#[test]
+fn insert_remove_and_contains() {
+    let mut component = Component::new(Tags::default());
+
+    for value in [3, 1, 2, 1] {
+        component.node().ordered.emit_insert(value);
+        component.node().hashed.emit_insert(value);
+    }
+    component.try_update();
+
+    assert_eq!(component.node().ordered.len(), 3);
+    assert_eq!(component.node().hashed.len(), 3);
+    assert!(component.node().ordered.contains(&2));
+    assert!(component.node().hashed.contains(&2));
+
+    component.node().ordered.emit_remove(2);
+    component.node().hashed.emit_remove(2);
+    component.node().hashed.emit_remove(9);
+    component.try_update();
+
+    assert!(!component.node().ordered.contains(&2));
+    assert!(!component.node().hashed.contains(&2));
+    assert_eq!(component.node().ordered.clone_state().unwrap(), BTreeSet::from([1, 3]));
+    assert_eq!(component.node().hashed.clone_state().unwrap(), HashSet::from([1, 3]));
+
+    component.node().ordered.emit_clear();
+    component.try_update();
+
+    assert!(component.node().ordered.is_empty());
+    assert_eq!(component.node().hashed.len(), 2);
+}
+
+#[test]
+fn state_replaces_whole_set() {
+    let mut component = Component::new(Tags::default());
+
+    component.node().ordered.emit_insert(1);
+    component.node().hashed.emit_insert(1);
+    component.try_update();
+
+    component.node().ordered.emit(BTreeSet::from([4, 5]));
+    component.node().hashed.emit(HashSet::from([6]));
+    component.try_update();
+
+    assert!(!component.node().ordered.contains(&1));
+    assert!(component.node().ordered.contains(&5));
+    assert_eq!(component.node().hashed.clone_state().unwrap(), HashSet::from([6]));
+}
+
+#[test]
+fn set_packets_apply_to_state() {
+    let mut from = Component::new(Tags::default());
+
+    from.node().ordered.emit_insert(1);
+    from.node().ordered.emit_insert(2);
+    from.node().hashed.emit(HashSet::from([3, 4]));
+    from.try_update();
+
+    from.node().ordered.emit_remove(1);
+    from.node().hashed.emit_insert(5);
+    from.node().hashed.emit_remove(3);
+    from.node().after.emit(7);
+    let output = from.try_update();
+
+    let mut to = Tags {
+        ordered: BTreeSet::from([1, 2]),
+        hashed: HashSet::from([3, 4]),
+        after: 0,
+    };
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        let message = tags::Message::from_packet(&packet, Key::default(), 0).unwrap();
+        message.apply_to(&mut to);
+    }
+
+    assert_eq!(to, from.node().clone_state().unwrap());
+    assert_eq!(to, Tags {
+        ordered: BTreeSet::from([2]),
+        hashed: HashSet::from([4, 5]),
+        after: 7,
+    });
+}
@@ -0,0 +1,70 @@
+use std::{cell::RefCell, time::Duration};
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+thread_local! {
+    // 같은 연쇄 안에서 상태로 emit 하면 중복 제거되므로 handler 호출을 직접 기록
+    static SEEN: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_seen() -> Vec<u32> {
+    SEEN.with(|seen| seen.take())
+}
+
+// burst 를 받으면 hit 을 emit_each 로, pulse 를 받으면 emit_signal 로 n 번 emit
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Node)]
+pub struct Pad {
+    pub hit: Signal<u32>,
+    pub burst: u32,
+    pub pulse: u32,
+}
+
+impl System for Pad {
+    fn handle(
+        node: Self::Node<'_>,
+        message: Self::Message,
+        delta: Option<Duration>,
+    ) {
+        use pad::Message::*;
+
+        match message {
+            Hit(signal::Message::Signal(value)) => SEEN.with(|seen| seen.borrow_mut().push(value)),
+            Burst(n) => (0..n).for_each(|value| node.hit.emit_each(value)),
+            Pulse(n) => (0..n).for_each(|value| node.hit.emit_signal(value)),
+            message => Self::fallback(node, message, delta),
+        }
+    }
+}
+
+#[test]
+fn emit_each_delivers_every_occurrence_in_a_cascade() {
+    let mut component = Component::new(Pad::default());
+    take_seen();
+
+    component.node().burst.emit(3);
+    let output = component.try_update();
+
+    let hits: Vec<_> = output.iter()
+        .filter(|packet| matches!(packet.message, pad::Message::Hit(_)))
+        .map(|packet| packet.key)
+        .collect();
+
+    // 연속된 emit 은 서로 다른 alt index 를 가져 중복 제거되지 않음
+    assert_eq!(hits.len(), 3);
+    assert!(hits.iter().all(|key| key.consist() == hits[0].consist()));
+    assert_ne!(hits[0].transient(), hits[1].transient());
+    assert_ne!(hits[1].transient(), hits[2].transient());
+    assert_ne!(hits[0].transient(), hits[2].transient());
+    assert_eq!(take_seen(), vec![0, 1, 2]);
+}
+
+#[test]
+fn emit_signal_deduplicates_in_a_cascade() {
+    let mut component = Component::new(Pad::default());
+    take_seen();
+
+    component.node().pulse.emit(3);
+    component.try_update();
+
+    assert_eq!(take_seen(), vec![0]);
+}
@@ -0,0 +1,41 @@
+use frand_node::ext::*;
+use frand_node::slab::{Generation, Handle};
+
+#[test]
+fn slot_retires_instead_of_wrapping() {
+    let mut slab = Slab::<u32>::default();
+
+    for generation in 0..Generation::MAX {
+        let handle = slab.prepare_insert().unwrap();
+        assert_eq!(handle, Handle { index: 0, generation });
+
+        assert!(slab.insert(handle, 1, generation as u32));
+        assert_eq!(slab.remove(handle), Some(generation as u32));
+    }
+
+    // 마지막 세대에 이른 슬롯은 다시 쓰이지 않으므로 처음의 handle 이 되살아나지 않음
+    let handle = slab.prepare_insert().unwrap();
+    assert_eq!(handle.index, 1);
+
+    assert!(!slab.insert(Handle { index: 0, generation: Generation::MAX }, 1, 0));
+    assert!(!slab.insert(Handle { index: 0, generation: 0 }, 1, 0));
+    assert!(slab.is_empty());
+}
+
+#[test]
+fn concurrent_inserts_converge() {
+    let handle = Handle { index: 0, generation: 0 };
+    let from_a = slab::Message::<u32>::Insert(handle, 1, 10);
+    let from_b = slab::Message::<u32>::Insert(handle, 2, 20);
+
+    let mut a = Slab::default();
+    from_a.apply_to(&mut a);
+    from_b.apply_to(&mut a);
+
+    let mut b = Slab::default();
+    from_b.apply_to(&mut b);
+    from_a.apply_to(&mut b);
+
+    assert_eq!(a.get(handle), Some(&20));
+    assert_eq!(b.get(handle), Some(&20));
+}
@@ -0,0 +1,81 @@
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Terminal)]
+pub enum Mode {
+    #[default]
+    Idle,
+    Named(String),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, Terminal)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Terminals {
+    pub mode: Mode,
+    pub point: Point,
+}
+
+impl System for Terminals {}
+
+#[test]
+fn derived_terminals_do_not_require_copy() {
+    let mut component = Component::new(Terminals::default());
+
+    component.node().mode.emit(Mode::Named("edit".to_string()));
+    component.node().point.emit(Point { x: 1, y: 2 });
+    component.try_update();
+
+    assert_eq!(component.node().mode.clone_state().unwrap(), Mode::Named("edit".to_string()));
+    assert_eq!(component.node().point.v(), Point { x: 1, y: 2 });
+}
+
+type Unit = Quantized<8, -1, 1>;
+
+#[test]
+fn quantized_clamps_to_min_and_max() {
+    assert_eq!(Unit::new(-5.0).step(), 0);
+    assert_eq!(Unit::new(-5.0).value(), -1.0);
+    assert_eq!(Unit::new(5.0).step(), Unit::STEPS);
+    assert_eq!(Unit::new(5.0).value(), 1.0);
+    assert_eq!(Unit::new(f32::NAN).value(), -1.0);
+    assert_eq!(Unit::from_step(u32::MAX).step(), Unit::STEPS);
+    assert_eq!(Quantized::<32, 0, 1>::STEPS, u32::MAX);
+}
+
+#[test]
+fn quantized_rounds_to_nearest_step() {
+    // 255 단계이므로 한 단계는 2/255
+    let step = 2.0 / 255.0;
+
+    assert_eq!(Unit::new(-1.0 + step * 0.49).step(), 0);
+    assert_eq!(Unit::new(-1.0 + step * 0.51).step(), 1);
+    assert_eq!(Unit::new(0.0).step(), 128);
+    assert!((Unit::new(0.3).value() - 0.3).abs() <= step / 2.0);
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Dial {
+    pub level: Unit,
+}
+
+impl System for Dial {}
+
+#[test]
+fn quantized_node_reads_quantized_value() {
+    let mut component = Component::new(Dial::default());
+
+    component.node().level.emit(Unit::new(2.0));
+    component.try_update();
+
+    assert_eq!(component.node().level.v(), 1.0);
+
+    component.node().level.emit(0.3.into());
+    component.try_update();
+
+    assert_eq!(component.node().level.v(), Unit::new(0.3).value());
+}
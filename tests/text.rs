@@ -0,0 +1,54 @@
+use frand_node::ext::*;
+
+fn messages(component: &mut Component<Text>) -> Vec<text::Message> {
+    component.try_update().into_iter().map(|packet| packet.message).collect()
+}
+
+#[test]
+fn concurrent_insert_delete_converges() {
+    let base = Text::from("hello");
+
+    let mut a = Component::new(base.clone()).with_replica(1);
+    let mut b = Component::new(base.clone()).with_replica(2);
+
+    a.node().emit_insert(5, " world");
+    a.node().emit_insert(0, ">");
+    let from_a = messages(&mut a);
+
+    b.node().emit_delete(0..1);
+    b.node().emit_insert(0, "J");
+    let mut from_b = messages(&mut b);
+
+    b.node().emit_insert(1, "!");
+    from_b.extend(messages(&mut b));
+
+    let mut state_a = a.node().clone_state().unwrap();
+    let mut state_b = b.node().clone_state().unwrap();
+
+    for message in &from_b { message.apply_to(&mut state_a); }
+    for message in from_a.iter().rev() { message.apply_to(&mut state_b); }
+
+    assert_eq!(state_a.to_string(), state_b.to_string());
+    assert!(state_a.to_string().ends_with("ello world"), "{state_a}");
+}
+
+#[test]
+fn state_message_merges() {
+    let base = Text::from("hello");
+
+    let mut a = base.clone();
+    let mut b = base.clone();
+
+    a.apply_insert(&a.prepare_insert(1, 5, " world"));
+    a.apply_delete(&a.prepare_delete(1..2));
+    b.apply_insert(&b.prepare_insert(2, 0, "oh "));
+    b.apply_delete(&b.prepare_delete(7..8));
+
+    let a_state = a.clone();
+
+    text::Message::State(b.clone()).apply_to(&mut a);
+    text::Message::State(a_state).apply_to(&mut b);
+
+    assert_eq!(a.to_string(), "oh hll world");
+    assert_eq!(a.to_string(), b.to_string());
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+use tokio::time::{advance, Instant};
+
+const PERIOD: Duration = Duration::from_millis(100);
+
+// input 을 받으면 value 에 throttle 하여 emit
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub input: u32,
+    pub value: u32,
+}
+
+impl System for Doc {
+    fn handle(
+        node: Self::Node<'_>,
+        message: Self::Message,
+        delta: Option<Duration>,
+    ) {
+        use doc::Message::*;
+
+        match message {
+            Input(value) => node.value.emit_throttled(value, PERIOD),
+            message => Self::fallback(node, message, delta),
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn throttle_applies_leading_and_trailing() {
+    let mut component = Component::new(Doc::default());
+    let start = Instant::now();
+
+    component.node().value.emit_throttled(1, PERIOD);
+    component.try_update();
+    assert_eq!(component.node().value.v(), 1);
+
+    // 주기 안의 값은 주기가 끝날 때 마지막 값만 적용됨
+    component.node().value.emit_throttled(2, PERIOD);
+    component.node().value.emit_throttled(3, PERIOD);
+    component.try_update();
+    assert_eq!(component.node().value.v(), 1);
+
+    component.update().await;
+    assert_eq!(component.node().value.v(), 3);
+    assert!(PERIOD <= start.elapsed());
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounce_resets_on_emit() {
+    let mut component = Component::new(Doc::default());
+
+    component.node().value.emit_debounced(1, PERIOD);
+    component.try_update();
+
+    advance(PERIOD * 6 / 10).await;
+    component.node().value.emit_debounced(2, PERIOD);
+    component.try_update();
+
+    // 처음 emit 으로부터는 PERIOD 가 지났지만 다시 emit 되어 미뤄짐
+    advance(PERIOD * 6 / 10).await;
+    component.try_update();
+    assert_eq!(component.node().value.v(), 0);
+
+    advance(PERIOD / 2).await;
+    component.try_update();
+    assert_eq!(component.node().value.v(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn replay_clears_throttle() {
+    let mut recorder = Recorder::new(Component::new(Doc::default()));
+
+    recorder.node().input.emit(1);
+    recorder.try_update();
+    // handler 가 emit 한 carry 는 다음 update 에 적용됨
+    recorder.try_update();
+    assert_eq!(recorder.node().value.v(), 1);
+
+    let (_, session) = recorder.into_inner();
+    let mut component = Replayer::new(session).replay().unwrap();
+
+    // replay 중 handler 가 시작한 throttle 주기가 남아 있지 않아야 함
+    component.node().value.emit_throttled(2, PERIOD);
+    component.try_update();
+    assert_eq!(component.node().value.v(), 2);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "time")]
+
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Times {
+    pub offset: OffsetDateTime,
+    pub primitive: PrimitiveDateTime,
+    pub date: Date,
+}
+
+impl System for Times {}
+
+#[test]
+fn time_terminals_round_trip() {
+    let now = time::OffsetDateTime::now_utc();
+    let mut component = Component::new(Times::default());
+
+    component.node().offset.emit(now.into());
+    component.node().primitive.emit(time::PrimitiveDateTime::new(now.date(), now.time()).into());
+    component.node().date.emit(now.date().into());
+    let output = component.try_update();
+
+    let mut state = Times::default();
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        times::Message::from_packet(&packet, Key::default(), 0).unwrap().apply_to(&mut state);
+    }
+
+    assert_eq!(state, component.node().clone_state().unwrap());
+    assert_eq!(state.offset.0, now);
+    assert_eq!(state.date.0, now.date());
+    assert_eq!(Date::default().0, time::OffsetDateTime::UNIX_EPOCH.date());
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+use tokio::time::{advance, Instant};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub value: u32,
+}
+
+impl System for Doc {}
+
+#[tokio::test(start_paused = true)]
+async fn emit_after_waits_for_duration() {
+    let mut component = Component::new(Doc::default());
+    let start = Instant::now();
+
+    component.node().value.emit_after(Duration::from_millis(100), 5);
+    component.update().await;
+    assert_eq!(component.node().value.v(), 0);
+
+    let output = component.update().await;
+    assert_eq!(output.len(), 1);
+    assert_eq!(component.node().value.v(), 5);
+    assert!(Duration::from_millis(100) <= start.elapsed());
+}
+
+#[tokio::test(start_paused = true)]
+async fn emit_interval_repeats_until_cancelled() {
+    let mut component = Component::new(Doc::default());
+    let start = Instant::now();
+
+    let mut count = 0;
+    let handle = component.node().value.emit_interval(Duration::from_millis(10), move || {
+        count += 1;
+        count
+    });
+    component.update().await;
+
+    for expected in 1..=3 {
+        component.update().await;
+        assert_eq!(component.node().value.v(), expected);
+    }
+    assert!(Duration::from_millis(30) <= start.elapsed());
+
+    handle.cancel();
+    advance(Duration::from_millis(100)).await;
+
+    assert!(component.try_update().is_empty());
+    assert_eq!(component.node().value.v(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancelled_emit_after_does_not_emit() {
+    let mut component = Component::new(Doc::default());
+
+    let handle = component.node().value.emit_after(Duration::from_millis(100), 5);
+    component.update().await;
+
+    handle.cancel();
+    advance(Duration::from_millis(200)).await;
+
+    assert!(component.try_update().is_empty());
+    assert_eq!(component.node().value.v(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn zero_period_interval_is_a_no_op() {
+    let mut component = Component::new(Doc::default());
+
+    let handle = component.node().value.emit_interval(Duration::ZERO, || 1);
+    assert!(handle.is_cancelled());
+    assert_eq!(component.node().value.try_emit_interval(Duration::ZERO, || 1).map(|handle| handle.is_finished()), Ok(true));
+
+    advance(Duration::from_millis(10)).await;
+    assert!(component.try_update().is_empty());
+    assert_eq!(component.node().value.v(), 0);
+}
+
+// value 를 받으면 handler 에 전달된 delta 를 elapsed 에 남김
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Timed {
+    pub value: u32,
+    pub elapsed: Duration,
+}
+
+impl System for Timed {
+    fn handle(
+        node: Self::Node<'_>,
+        message: Self::Message,
+        delta: Option<Duration>,
+    ) {
+        use timed::Message::*;
+
+        match message {
+            Value(_) => node.elapsed.emit(delta.unwrap_or_default()),
+            message => Self::fallback(node, message, delta),
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn future_delta_follows_tokio_clock() {
+    let mut component = Component::new(Timed::default());
+
+    component.node().value.emit_after(Duration::from_millis(100), 5);
+    component.update().await;
+    component.update().await;
+
+    // 멈춘 시계에서 자동으로 진행된 시간만큼이 delta 로 전달되어야 함
+    assert_eq!(component.node().value.v(), 5);
+    assert_eq!(component.node().elapsed.v(), Duration::from_millis(100));
+
+    component.node().value.emit_interval(Duration::from_millis(40), || 6);
+    component.update().await;
+    component.update().await;
+
+    assert_eq!(component.node().elapsed.v(), Duration::from_millis(40));
+}
@@ -0,0 +1,65 @@
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Pair {
+    pub pair: (u32, i64),
+    pub triple: (u32, bool, Vec<u32>),
+    pub after: u32,
+}
+
+impl System for Pair {}
+
+#[test]
+fn tuple_items_emit_and_deref() {
+    let mut component = Component::new(Pair::default());
+
+    component.node().pair.0.emit(1);
+    component.node().pair.1.emit(-1);
+    component.node().triple.2.emit_push(3);
+    component.node().after.emit(7);
+    component.try_update();
+
+    assert_eq!(component.node().pair.0.v(), 1);
+    assert_eq!(component.node().pair.1.v(), -1);
+    assert_eq!(component.node().triple.2.len(), 1);
+    assert_eq!(component.node().after.v(), 7);
+
+    component.node().triple.emit((2, true, vec![4, 5]));
+    component.try_update();
+
+    assert_eq!(component.node().triple.clone_state().unwrap(), (2, true, vec![4, 5]));
+    assert!(component.node().triple.1.v());
+}
+
+#[test]
+fn tuple_packets_apply_to_state() {
+    let mut from = Component::new(Pair::default());
+
+    from.node().pair.emit((1, -1));
+    from.try_update();
+
+    from.node().pair.1.emit(-2);
+    from.node().triple.0.emit(3);
+    from.node().triple.2.emit_push(4);
+    from.node().after.emit(9);
+    let output = from.try_update();
+
+    let mut to = Pair {
+        pair: (1, -1),
+        ..Default::default()
+    };
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        let message = pair::Message::from_packet(&packet, Key::default(), 0).unwrap();
+        message.apply_to(&mut to);
+    }
+
+    assert_eq!(to, from.node().clone_state().unwrap());
+    assert_eq!(to, Pair {
+        pair: (1, -2),
+        triple: (3, false, vec![4]),
+        after: 9,
+    });
+}
@@ -0,0 +1,45 @@
+use frand_node::ext::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Node)]
+pub struct Doc {
+    pub items: Vec<u32>,
+    pub after: u32,
+}
+
+impl System for Doc {}
+
+#[test]
+fn sibling_after_vec_round_trips() {
+    let mut from = Component::new(Doc::default());
+
+    from.node().items.emit_push(1);
+    from.node().items.emit_push(2);
+    from.node().after.emit(7);
+    from.try_update();
+
+    from.node().items.item(1).emit(5);
+    from.node().items.emit_pop();
+    from.node().after.emit(9);
+    let output = from.try_update();
+
+    let mut to = Doc {
+        items: vec![1, 2],
+        after: 7,
+    };
+
+    for packet in output {
+        let packet = packet.message.to_packet(packet.key);
+        let message = doc::Message::from_packet(&packet, Key::default(), 0).unwrap();
+
+        // Vec 의 Push 와 Pop 이 뒤의 형제 노드와 같은 id 를 쓰지 않아야 함
+        if packet.key().consist().id() == from.node().after.consist().id() {
+            assert!(matches!(message, doc::Message::After(_)), "{message:?}");
+        }
+
+        message.apply_to(&mut to);
+    }
+
+    assert_eq!(to, from.node().clone_state().unwrap());
+    assert_eq!(to, Doc { items: vec![1], after: 9 });
+}